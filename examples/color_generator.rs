@@ -1,36 +1,34 @@
-// Uses a Property<[f32; 4]> as a color generator
+// Uses a Property<Oklcha> as a color generator, run with `--features color`
 use bevy::prelude::*;
 
 use bevy_variable_property::prelude::*;
 
 fn main() {
-    let properties = [
+    let properties: [(&str, Property<Oklcha>); 3] = [
         (
-            "Generating colors in the (inclusive) range: R: 0.0-0.5, G: 0.5-1.0, B: 0.0-0.2, A: 1.0-1.0",
-            ([0.0f32, 0.5, 0.0, 1.0]..=[0.5, 1.0, 0.2, 1.0]).into()
-        ),
-        (
-            "Generating entirely random colors",
-            Property::Random,
+            "Generating colors between a deep red and a light orange, interpolated in Oklch",
+            (Oklcha::lch(0.4, 0.15, 20.0)..=Oklcha::lch(0.8, 0.15, 70.0)).into(),
         ),
+        ("Generating entirely random colors", Property::Random),
         (
             "Generating colors based on a predefined list",
-                vec![
-                    [0.0, 0.5, 1.0, 1.0],
-                    [0.3, 0.9, 0.5, 1.0],
-                    [0.5, 0.3, 0.3, 1.0],
-                    [0.8, 0.2, 0.9, 1.0],
-                    [1.0, 1.0, 0.9, 0.8],
-                ].into()
-        )
+            vec![
+                Oklcha::lch(0.5, 0.1, 250.0),
+                Oklcha::lch(0.7, 0.2, 140.0),
+                Oklcha::lch(0.6, 0.1, 30.0),
+                Oklcha::lch(0.8, 0.1, 320.0),
+                Oklcha::new(0.9, 0.05, 90.0, 0.8),
+            ]
+            .into(),
+        ),
     ];
 
     for (description, p) in properties.into_iter() {
         println!("{}", description);
         println!("===============================");
         for _ in 0..5 {
-            let color = LinearRgba::from_f32_array(p.get_value());
-            println!("{:?}", color);
+            let color = Srgba::from(p.get_value());
+            println!("{}", color.to_hex());
         }
         println!("===============================\n");
     }
@@ -0,0 +1,314 @@
+//! Derive macros for `bevy_variable_property`, re-exported from there.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput,
+    Fields, Index, Member, Path, PathArguments,
+};
+
+/// Derives `PropRand` for a struct by generating each field independently.
+///
+/// Ranges are sampled per field, between the start and end's value for that field.
+///
+/// If `bevy_variable_property` is renamed or re-exported, give its path with
+/// `#[prop_rand(crate = path)]`.
+#[proc_macro_derive(PropRand, attributes(prop_rand))]
+pub fn derive_prop_rand(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_prop_rand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `VariableProperty` for a struct whose fields are all `VariableProperty`s.
+///
+/// The output type is given with `#[variable_property(output = Type)]` and must have a field with
+/// the same name, or position for tuple structs, for each of this struct's fields, holding that
+/// field's output. A renamed or re-exported `bevy_variable_property` is given with
+/// `#[variable_property(crate = path)]`.
+#[proc_macro_derive(VariableProperty, attributes(variable_property))]
+pub fn derive_variable_property(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_variable_property(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_prop_rand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut krate = None;
+    parse_attrs(&input, "prop_rand", |meta| {
+        if meta.path.is_ident("crate") {
+            krate = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `crate`"))
+        }
+    })?;
+    let krate = krate_path(krate);
+    let fields = struct_fields(&input)?;
+    let ident = &input.ident;
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: #krate::prop_rand::PropRand));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let members = members(fields);
+    let tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let gen = construct(
+        fields,
+        tys.iter()
+            .map(|ty| quote!(<#ty as #krate::prop_rand::PropRand>::gen(rng))),
+    );
+    let gen_range = construct(
+        fields,
+        tys.iter().zip(&members).map(|(ty, member)| {
+            quote!(<#ty as #krate::prop_rand::PropRand>::gen_range(
+                rng,
+                #krate::prop_range::PropRange::new(range.start.#member, range.end.#member, range.inclusive),
+            ))
+        }),
+    );
+    let gen_distribution = construct(
+        fields,
+        tys.iter().zip(&members).map(|(ty, member)| {
+            quote!(<#ty as #krate::prop_rand::PropRand>::gen_distribution(
+                rng,
+                #krate::prop_range::PropRange::new(range.start.#member, range.end.#member, range.inclusive),
+                distribution,
+            ))
+        }),
+    );
+    let from_units = construct(
+        fields,
+        tys.iter().zip(&members).map(|(ty, member)| {
+            quote!(<#ty as #krate::prop_rand::PropRand>::from_units(
+                #krate::prop_range::PropRange::new(range.start.#member, range.end.#member, range.inclusive),
+                units,
+            )?)
+        }),
+    );
+    let interpolate = construct(
+        fields,
+        tys.iter().zip(&members).map(|(ty, member)| {
+            quote!(<#ty as #krate::prop_rand::PropRand>::interpolate(&from.#member, &to.#member, t)?)
+        }),
+    );
+    let validate_bounds = tys.iter().zip(&members).map(|(ty, member)| {
+        quote!(<#ty as #krate::prop_rand::PropRand>::validate_bounds(
+            &start.#member,
+            &end.#member,
+            inclusive,
+        )?;)
+    });
+    let fix_bounds = tys.iter().zip(&members).map(|(ty, member)| {
+        quote!(<#ty as #krate::prop_rand::PropRand>::fix_bounds(
+            &mut start.#member,
+            &mut end.#member,
+        );)
+    });
+
+    Ok(quote! {
+        impl #impl_generics #krate::prop_rand::PropRand for #ident #ty_generics #where_clause {
+            fn gen<R: #krate::__rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                #gen
+            }
+
+            fn gen_range<R: #krate::__rand::RngCore + ?Sized>(
+                rng: &mut R,
+                range: #krate::prop_range::PropRange<Self>,
+            ) -> Self {
+                #gen_range
+            }
+
+            fn gen_distribution<R: #krate::__rand::RngCore + ?Sized>(
+                rng: &mut R,
+                range: #krate::prop_range::PropRange<Self>,
+                distribution: &#krate::prop_distribution::PropDistribution,
+            ) -> Self {
+                #gen_distribution
+            }
+
+            fn from_units<F: FnMut() -> f64>(
+                range: #krate::prop_range::PropRange<Self>,
+                units: &mut F,
+            ) -> ::core::option::Option<Self> {
+                ::core::option::Option::Some(#from_units)
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                Some(#interpolate)
+            }
+
+            fn validate_bounds(
+                start: &Self,
+                end: &Self,
+                inclusive: bool,
+            ) -> Result<(), #krate::property_error::PropertyError> {
+                #(#validate_bounds)*
+                Ok(())
+            }
+
+            fn fix_bounds(start: &mut Self, end: &mut Self) {
+                #(#fix_bounds)*
+            }
+        }
+    })
+}
+
+fn expand_variable_property(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let (output, krate) = variable_property_attrs(&input)?;
+    let krate = krate_path(krate);
+    let fields = struct_fields(&input)?;
+    let ident = &input.ident;
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: #krate::variable_property::VariableProperty));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let members = members(fields);
+    let build_output = |values: Vec<TokenStream2>| match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote!(#output { #(#idents: #values,)* })
+        }
+        _ => quote!(#output ( #(#values,)* )),
+    };
+    let build = |method: TokenStream2| {
+        build_output(
+            members
+                .iter()
+                .map(|member| quote!(self.#member.#method(rng)))
+                .collect(),
+        )
+    };
+    let get_value = build(quote!(get_value_with));
+    let next_value = build(quote!(next_value_with));
+    let interpolate = build_output(
+        fields
+            .iter()
+            .zip(&members)
+            .map(|(field, member)| {
+                let ty = &field.ty;
+                quote!(<#ty as #krate::variable_property::VariableProperty>::interpolate(
+                    &from.#member,
+                    &to.#member,
+                    t,
+                )?)
+            })
+            .collect(),
+    );
+
+    Ok(quote! {
+        impl #impl_generics #krate::variable_property::VariableProperty for #ident #ty_generics #where_clause {
+            type Output = #output;
+
+            fn get_value_with(&self, rng: &mut dyn #krate::__rand::RngCore) -> Self::Output {
+                #get_value
+            }
+
+            fn next_value_with(&mut self, rng: &mut dyn #krate::__rand::RngCore) -> Self::Output {
+                #next_value
+            }
+
+            fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+                Some(#interpolate)
+            }
+        }
+    })
+}
+
+/// Structs without fields are rejected, as there would be nothing to generate.
+fn struct_fields(input: &DeriveInput) -> syn::Result<&Fields> {
+    match &input.data {
+        Data::Struct(data) if data.fields.is_empty() => Err(syn::Error::new(
+            input.ident.span(),
+            "can only be derived for structs with fields",
+        )),
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new(
+            input.ident.span(),
+            "can only be derived for structs",
+        )),
+    }
+}
+
+fn members(fields: &Fields) -> Vec<Member> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect()
+}
+
+/// Builds `Self` out of one expression per field.
+fn construct(fields: &Fields, values: impl Iterator<Item = TokenStream2>) -> TokenStream2 {
+    match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote!(Self { #(#idents: #values,)* })
+        }
+        _ => quote!(Self ( #(#values,)* )),
+    }
+}
+
+/// The path to `bevy_variable_property`, given by a `crate = path` attribute argument.
+fn krate_path(krate: Option<Path>) -> TokenStream2 {
+    krate.map_or_else(|| quote!(::bevy_variable_property), |krate| quote!(#krate))
+}
+
+/// Runs `parse` on each argument of the `name` attributes.
+fn parse_attrs(
+    input: &DeriveInput,
+    name: &str,
+    mut parse: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident(name)) {
+        attr.parse_nested_meta(&mut parse)?;
+    }
+    Ok(())
+}
+
+/// Parses `#[variable_property(output = Type, crate = path)]`, with any generic arguments of the
+/// output turned into turbofish form so the path can also be used to construct the output.
+fn variable_property_attrs(input: &DeriveInput) -> syn::Result<(Path, Option<Path>)> {
+    let (mut output, mut krate): (Option<Path>, _) = (None, None);
+    parse_attrs(input, "variable_property", |meta| {
+        if meta.path.is_ident("output") {
+            output = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("crate") {
+            krate = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `output` or `crate`"))
+        }
+    })?;
+    let mut output = output.ok_or_else(|| {
+        syn::Error::new(
+            input.span(),
+            "missing `#[variable_property(output = Type)]` attribute",
+        )
+    })?;
+    for segment in output.segments.iter_mut() {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token.get_or_insert_with(Default::default);
+        }
+    }
+    Ok((output, krate))
+}
@@ -0,0 +1,207 @@
+use bevy_math::*;
+use bevy_reflect::{Reflect, TypePath};
+use rand::RngCore;
+
+use std::ops::{Add, Mul};
+
+use crate::prop_rand::PropRand;
+use crate::variable_property::VariableProperty;
+
+/// Applies a function to each generated value. See [VariableProperty::map].
+///
+/// The output is a [PropRand] so transitions can interpolate the mapped values.
+///
+/// The function isn't reflected, so this can't be created through reflection.
+#[derive(Reflect, Clone)]
+#[reflect(from_reflect = false)]
+pub struct PropMap<P: VariableProperty, U> {
+    pub property: P,
+    #[reflect(ignore)]
+    pub f: fn(P::Output) -> U,
+}
+
+impl<P: VariableProperty, U: PropRand + TypePath> VariableProperty for PropMap<P, U> {
+    type Output = U;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> U {
+        (self.f)(self.property.get_value_with(rng))
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> U {
+        (self.f)(self.property.next_value_with(rng))
+    }
+
+    /// Interpolates the mapped values, see [PropRand::interpolate].
+    fn interpolate(from: &U, to: &U, t: f32) -> Option<U> {
+        U::interpolate(from, to, t)
+    }
+}
+
+/// Generates a value from both properties as a tuple. See [VariableProperty::zip].
+#[derive(Reflect, Clone)]
+pub struct PropZip<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A: VariableProperty, B: VariableProperty> VariableProperty for PropZip<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output {
+        (self.a.get_value_with(rng), self.b.get_value_with(rng))
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+        (self.a.next_value_with(rng), self.b.next_value_with(rng))
+    }
+
+    fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+        Some((
+            A::interpolate(&from.0, &to.0, t)?,
+            B::interpolate(&from.1, &to.1, t)?,
+        ))
+    }
+}
+
+/// Adds the values of both properties. See [VariableProperty::add].
+#[derive(Reflect, Clone)]
+pub struct PropAdd<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> VariableProperty for PropAdd<A, B>
+where
+    A: VariableProperty,
+    B: VariableProperty,
+    A::Output: Add<B::Output>,
+    <A::Output as Add<B::Output>>::Output: PropRand + TypePath,
+{
+    type Output = <A::Output as Add<B::Output>>::Output;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output {
+        self.a.get_value_with(rng) + self.b.get_value_with(rng)
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+        self.a.next_value_with(rng) + self.b.next_value_with(rng)
+    }
+
+    /// Interpolates the sums, see [PropRand::interpolate].
+    fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+        <Self::Output as PropRand>::interpolate(from, to, t)
+    }
+}
+
+/// Multiplies the values of both properties. See [VariableProperty::mul].
+#[derive(Reflect, Clone)]
+pub struct PropMul<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> VariableProperty for PropMul<A, B>
+where
+    A: VariableProperty,
+    B: VariableProperty,
+    A::Output: Mul<B::Output>,
+    <A::Output as Mul<B::Output>>::Output: PropRand + TypePath,
+{
+    type Output = <A::Output as Mul<B::Output>>::Output;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output {
+        self.a.get_value_with(rng) * self.b.get_value_with(rng)
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+        self.a.next_value_with(rng) * self.b.next_value_with(rng)
+    }
+
+    /// Interpolates the products, see [PropRand::interpolate].
+    fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+        <Self::Output as PropRand>::interpolate(from, to, t)
+    }
+}
+
+/// Clamps each generated value between `min` and `max`. See [VariableProperty::clamp].
+#[derive(Reflect, Clone)]
+pub struct PropClamp<P: VariableProperty> {
+    pub property: P,
+    pub min: P::Output,
+    pub max: P::Output,
+}
+
+impl<P> VariableProperty for PropClamp<P>
+where
+    P: VariableProperty,
+    P::Output: ClampValue + Clone,
+{
+    type Output = P::Output;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output {
+        self.property
+            .get_value_with(rng)
+            .clamp_value(self.min.clone(), self.max.clone())
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+        self.property
+            .next_value_with(rng)
+            .clamp_value(self.min.clone(), self.max.clone())
+    }
+
+    fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+        P::interpolate(from, to, t)
+    }
+}
+
+/// Generates a value, then generates the output from the property the function builds out of it.
+/// See [VariableProperty::then].
+///
+/// The function isn't reflected, so this can't be created through reflection.
+#[derive(Reflect, Clone)]
+#[reflect(from_reflect = false)]
+pub struct PropThen<P: VariableProperty, Q> {
+    pub property: P,
+    #[reflect(ignore)]
+    pub f: fn(P::Output) -> Q,
+}
+
+impl<P: VariableProperty, Q: VariableProperty> VariableProperty for PropThen<P, Q> {
+    type Output = Q::Output;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Q::Output {
+        (self.f)(self.property.get_value_with(rng)).get_value_with(rng)
+    }
+
+    /// Only advances the first property, the built one is discarded after generating the output.
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Q::Output {
+        (self.f)(self.property.next_value_with(rng)).get_value_with(rng)
+    }
+
+    fn interpolate(from: &Q::Output, to: &Q::Output, t: f32) -> Option<Q::Output> {
+        Q::interpolate(from, to, t)
+    }
+}
+
+/// Clamping for the values of a [PropClamp]. Vectors are clamped per element.
+pub trait ClampValue {
+    fn clamp_value(self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! clamp_value_impl_many {
+    ($($type:tt,)+) => {
+        $(
+            impl ClampValue for $type {
+                fn clamp_value(self, min: Self, max: Self) -> Self {
+                    self.clamp(min, max)
+                }
+            }
+        )+
+    };
+}
+
+clamp_value_impl_many!(
+    usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, Vec2, Vec3, Vec4,
+    UVec2, UVec3, UVec4, IVec2, IVec3, IVec4, DVec2, DVec3, DVec4,
+);
@@ -0,0 +1,313 @@
+use core::ops::Deref;
+
+#[cfg(feature = "serde")]
+use bevy_reflect::serde::{ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry};
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, TypeRegistry};
+use rand::{thread_rng, RngCore};
+
+use crate::variable_property::VariableProperty;
+
+/// The object safe part of [VariableProperty], for any property that can be reflected.
+trait ErasedVariableProperty: Send + Sync {
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect>;
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect>;
+    fn as_partial_reflect(&self) -> &dyn PartialReflect;
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect;
+    fn clone_box(&self) -> Box<dyn ErasedVariableProperty>;
+    fn interpolate_fn(&self) -> InterpolateFn;
+}
+
+/// [VariableProperty::interpolate] for the erased output type.
+type InterpolateFn =
+    fn(&dyn PartialReflect, &dyn PartialReflect, f32) -> Option<Box<dyn PartialReflect>>;
+
+impl<T> ErasedVariableProperty for T
+where
+    T: VariableProperty<Output: FromReflect> + PartialReflect + Clone,
+{
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect> {
+        Box::new(VariableProperty::get_value_with(self, rng))
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect> {
+        Box::new(VariableProperty::next_value_with(self, rng))
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedVariableProperty> {
+        Box::new(self.clone())
+    }
+
+    fn interpolate_fn(&self) -> InterpolateFn {
+        |from, to, t| {
+            let from = T::Output::from_reflect(from)?;
+            let to = T::Output::from_reflect(to)?;
+            Some(Box::new(T::interpolate(&from, &to, t)?))
+        }
+    }
+}
+
+/// Wraps any [VariableProperty] with a reflected output, generating boxed values, so properties
+/// of different types can be stored together, ie: in an editor panel or a list of modifiers.
+///
+/// As a [VariableProperty] it generates [DynamicValue]s, so it can be driven by an
+/// [IntervalProperty](crate::interval_property::IntervalProperty), ie: in a
+/// [PropertyBinding](crate::property_binding::PropertyBinding). With the `serde` feature it's
+/// serialized by the wrapped property's type path, which must be registered with
+/// [ReflectVariableProperty] to be deserialized.
+#[derive(Reflect)]
+#[reflect(opaque, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+pub struct DynamicProperty(Box<dyn ErasedVariableProperty>);
+
+impl DynamicProperty {
+    pub fn new<T>(property: T) -> Self
+    where
+        T: VariableProperty<Output: FromReflect> + PartialReflect + Clone,
+    {
+        Self(Box::new(property))
+    }
+
+    /// Creates a property from a reflected value of a type registered with
+    /// [ReflectVariableProperty], ie: one deserialized from a scene file.
+    pub fn from_reflect(property: &dyn PartialReflect, registry: &TypeRegistry) -> Option<Self> {
+        let type_id = property.get_represented_type_info()?.type_id();
+        registry
+            .get_type_data::<ReflectVariableProperty>(type_id)?
+            .to_dynamic(property)
+    }
+
+    /// Generates a value using the thread-local rng.
+    pub fn get_value(&self) -> Box<dyn PartialReflect> {
+        self.get_value_with(&mut thread_rng())
+    }
+
+    /// See [VariableProperty::get_value_with].
+    pub fn get_value_with(&self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect> {
+        self.0.get_value_with(rng)
+    }
+
+    /// Generates the next value using the thread-local rng.
+    pub fn next_value(&mut self) -> Box<dyn PartialReflect> {
+        self.next_value_with(&mut thread_rng())
+    }
+
+    /// See [VariableProperty::next_value_with].
+    pub fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Box<dyn PartialReflect> {
+        self.0.next_value_with(rng)
+    }
+
+    /// The wrapped property, ie: to show it in an inspector.
+    pub fn property(&self) -> &dyn PartialReflect {
+        self.0.as_partial_reflect()
+    }
+
+    /// The wrapped property, ie: to edit it from an inspector.
+    pub fn property_mut(&mut self) -> &mut dyn PartialReflect {
+        self.0.as_partial_reflect_mut()
+    }
+}
+
+impl Clone for DynamicProperty {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl VariableProperty for DynamicProperty {
+    type Output = DynamicValue;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> DynamicValue {
+        DynamicValue {
+            value: self.0.get_value_with(rng),
+            interpolate: Some(self.0.interpolate_fn()),
+        }
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> DynamicValue {
+        DynamicValue {
+            value: self.0.next_value_with(rng),
+            interpolate: Some(self.0.interpolate_fn()),
+        }
+    }
+
+    fn interpolate(from: &DynamicValue, to: &DynamicValue, t: f32) -> Option<DynamicValue> {
+        let interpolate = to.interpolate.or(from.interpolate)?;
+        Some(DynamicValue {
+            value: interpolate(from.as_ref(), to.as_ref(), t)?,
+            interpolate: Some(interpolate),
+        })
+    }
+}
+
+/// A value generated by a [DynamicProperty], derefs to the boxed value.
+///
+/// Values deserialized with the `serde` feature don't know how to interpolate, so a transition
+/// starting from one switches on finish instead.
+#[derive(Reflect)]
+#[reflect(opaque, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+pub struct DynamicValue {
+    value: Box<dyn PartialReflect>,
+    interpolate: Option<InterpolateFn>,
+}
+
+impl DynamicValue {
+    /// Wraps a value, ie: as the initial value of an
+    /// [IntervalProperty](crate::interval_property::IntervalProperty). Values created this way
+    /// are eased with the interpolation of the values they transition to.
+    pub fn new<T: PartialReflect>(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+            interpolate: None,
+        }
+    }
+
+    pub fn into_inner(self) -> Box<dyn PartialReflect> {
+        self.value
+    }
+}
+
+impl Deref for DynamicValue {
+    type Target = dyn PartialReflect;
+
+    fn deref(&self) -> &Self::Target {
+        self.value.as_ref()
+    }
+}
+
+impl AsRef<dyn PartialReflect> for DynamicValue {
+    fn as_ref(&self) -> &dyn PartialReflect {
+        self.value.as_ref()
+    }
+}
+
+impl Clone for DynamicValue {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.reflect_clone().map_or_else(
+                |_| self.value.to_dynamic(),
+                |value| value.into_partial_reflect(),
+            ),
+            interpolate: self.interpolate,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use bevy_reflect::{
+        serde::{
+            DeserializeWithRegistry, ReflectDeserializer, ReflectSerializer, SerializeWithRegistry,
+        },
+        TypeRegistry,
+    };
+    use serde::{de::DeserializeSeed, de::Error, Deserializer, Serialize, Serializer};
+
+    use super::{DynamicProperty, DynamicValue};
+
+    impl SerializeWithRegistry for DynamicProperty {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S,
+            registry: &TypeRegistry,
+        ) -> Result<S::Ok, S::Error> {
+            ReflectSerializer::new(self.property(), registry).serialize(serializer)
+        }
+    }
+
+    impl<'de> DeserializeWithRegistry<'de> for DynamicProperty {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+            registry: &TypeRegistry,
+        ) -> Result<Self, D::Error> {
+            let property = ReflectDeserializer::new(registry).deserialize(deserializer)?;
+            DynamicProperty::from_reflect(property.as_ref(), registry).ok_or_else(|| {
+                D::Error::custom(format!(
+                    "`{}` isn't registered with `ReflectVariableProperty`",
+                    property.reflect_type_path()
+                ))
+            })
+        }
+    }
+
+    impl SerializeWithRegistry for DynamicValue {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S,
+            registry: &TypeRegistry,
+        ) -> Result<S::Ok, S::Error> {
+            ReflectSerializer::new(self.as_ref(), registry).serialize(serializer)
+        }
+    }
+
+    impl<'de> DeserializeWithRegistry<'de> for DynamicValue {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+            registry: &TypeRegistry,
+        ) -> Result<Self, D::Error> {
+            Ok(DynamicValue {
+                value: ReflectDeserializer::new(registry).deserialize(deserializer)?,
+                interpolate: None,
+            })
+        }
+    }
+}
+
+/// Type data for sampling reflected properties without knowing their type, registered with
+/// `app.register_type_data::<Property<f32>, ReflectVariableProperty>()` or
+/// [IntervalPropertyAppExt::register_variable_property](crate::plugin::IntervalPropertyAppExt::register_variable_property).
+///
+/// The properties given can be the registered type itself, or a dynamic value representing it,
+/// ie: one deserialized from a scene file.
+#[derive(Clone)]
+pub struct ReflectVariableProperty {
+    to_dynamic: fn(&dyn PartialReflect) -> Option<DynamicProperty>,
+}
+
+impl ReflectVariableProperty {
+    /// Generates a value from the property using the thread-local rng, or None if it isn't the
+    /// registered type.
+    pub fn get_value(&self, property: &dyn PartialReflect) -> Option<Box<dyn PartialReflect>> {
+        self.get_value_with(property, &mut thread_rng())
+    }
+
+    /// Same as [ReflectVariableProperty::get_value], using the given rng.
+    pub fn get_value_with(
+        &self,
+        property: &dyn PartialReflect,
+        rng: &mut dyn RngCore,
+    ) -> Option<Box<dyn PartialReflect>> {
+        Some(self.to_dynamic(property)?.get_value_with(rng))
+    }
+
+    /// Wraps a copy of the property in a [DynamicProperty], or None if it isn't the registered
+    /// type.
+    pub fn to_dynamic(&self, property: &dyn PartialReflect) -> Option<DynamicProperty> {
+        (self.to_dynamic)(property)
+    }
+}
+
+impl<T> FromType<T> for ReflectVariableProperty
+where
+    T: VariableProperty<Output: FromReflect> + FromReflect + Clone,
+{
+    fn from_type() -> Self {
+        Self {
+            to_dynamic: |property| Some(DynamicProperty::new(T::from_reflect(property)?)),
+        }
+    }
+}
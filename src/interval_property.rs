@@ -1,75 +1,354 @@
 use bevy_ecs::{component::Mutable, prelude::*, reflect::ReflectComponent};
+use bevy_math::curve::{Curve, EaseFunction};
 use bevy_reflect::{Reflect, TypePath};
 use bevy_time::{Time, Timer, TimerMode};
 use core::time::Duration;
+use rand::{thread_rng, RngCore};
 
+use crate::property_target::{PropertyTargetData, PropertyTargetMode, PropertyTargetQuery};
+use crate::rng::{PropertyRng, PropertyRngSeed};
 use crate::variable_property::VariableProperty;
+use crate::Property;
 
 /// A field that generates a new value on an interval.
+///
+/// The interval can itself vary, ie: a [Property] of 0.5..2.0 seconds is sampled again each time
+/// the timer finishes, see [IntervalProperty::new_with_interval].
 #[derive(Reflect)]
-pub struct IntervalProperty<T: VariableProperty + TypePath> {
+pub struct IntervalProperty<
+    T: VariableProperty + TypePath,
+    I: VariableProperty<Output = f32> + TypePath = Property<f32>,
+> {
     property: T,
+    /// Seconds between new values.
+    interval: I,
     timer: Timer,
     curr: Option<T::Output>,
+    /// Eases from the previous value to the next over each interval, see
+    /// [IntervalProperty::set_transition].
+    transition: Option<EaseFunction>,
+    /// The values being eased between.
+    from: Option<T::Output>,
+    to: Option<T::Output>,
+    /// Generates a value on the next tick, see [IntervalProperty::force_regenerate].
+    force: bool,
+    generated: bool,
+    /// A value was generated while paused with [PauseMode::Queue], to be applied on resume.
+    queued: bool,
+    /// The current interval is generated on the next tick, with the rng used for the values.
+    pending_interval: bool,
 }
 
-impl<T: VariableProperty + TypePath> IntervalProperty<T> {
+impl<T, I> IntervalProperty<T, I>
+where
+    T: VariableProperty + TypePath,
+    I: VariableProperty<Output = f32> + TypePath,
+{
+    /// Same as [IntervalProperty::new], with any property generating the interval in seconds.
+    ///
+    /// Every interval, including the first, is generated with the rng used for the values, so
+    /// the first one is only generated on the first tick. Until then [IntervalProperty::duration]
+    /// is zero.
+    pub fn new_with_interval(property: T, interval: I) -> Self {
+        Self {
+            property,
+            interval,
+            timer: Timer::new(Duration::ZERO, TimerMode::Repeating),
+            curr: None,
+            transition: None,
+            from: None,
+            to: None,
+            force: false,
+            generated: false,
+            queued: false,
+            pending_interval: true,
+        }
+    }
+
     /// Ticks the internal timer by the given delta and will generate a new value if the timer
     /// has finished and return a reference to it.
+    ///
+    /// With a transition set, the value changes on every tick instead, and is returned each time.
     pub fn tick_value(&mut self, delta: Duration) -> Option<&T::Output> {
+        self.tick_value_with(delta, &mut thread_rng())
+    }
+
+    /// Same as [IntervalProperty::tick_value], but generates new values using the given rng.
+    pub fn tick_value_with(
+        &mut self,
+        delta: Duration,
+        rng: &mut dyn RngCore,
+    ) -> Option<&T::Output> {
+        if std::mem::take(&mut self.pending_interval) {
+            let duration = interval_duration(self.interval.next_value_with(rng));
+            self.timer.set_duration(duration);
+        }
         self.timer.tick(delta);
-        if self.timer.just_finished() {
-            self.curr = Some(self.property.get_value());
-            self.get_curr_value()
-        } else {
-            None
+        let finished = self.timer.just_finished() || std::mem::take(&mut self.force);
+        self.generated = finished;
+        if finished {
+            if !self.timer.just_finished() {
+                self.timer.reset();
+            }
+            let duration = interval_duration(self.interval.next_value_with(rng));
+            self.timer.set_duration(duration);
+        }
+        let Some(ease) = self.transition else {
+            if finished {
+                self.curr = Some(self.property.next_value_with(rng));
+                return self.get_curr_value();
+            }
+            return None;
+        };
+        if finished {
+            // Starts from wherever the last transition ended, or the current value for the first.
+            self.from = self.to.take().or(self.curr.take());
+            self.to = Some(self.property.next_value_with(rng));
+        }
+        let to = self.to.as_ref()?;
+        let from = self.from.as_ref().unwrap_or(to);
+        match T::interpolate(from, to, ease.sample_clamped(self.timer.fraction())) {
+            Some(value) => self.curr = Some(value),
+            // Values that can't be interpolated switch instantly, as without a transition.
+            None if finished => {
+                self.curr = self.to.take();
+                self.from = None;
+            }
+            None => return None,
         }
+        self.get_curr_value()
     }
 
     /// Returns a reference to the current value if one has been set yet.
     pub fn get_curr_value(&self) -> Option<&T::Output> {
         self.curr.as_ref()
     }
+
+    /// Returns a reference to the most recently generated value, which is ahead of the current
+    /// value while a transition eases towards it.
+    pub fn get_target_value(&self) -> Option<&T::Output> {
+        self.to.as_ref().or(self.curr.as_ref())
+    }
+
+    /// Whether the last tick generated a new value.
+    pub fn just_generated(&self) -> bool {
+        self.generated
+    }
+
+    pub fn property(&self) -> &T {
+        &self.property
+    }
+
+    /// Mutable access to the property, ie: to narrow a range at runtime. Takes effect on the next
+    /// generated value.
+    pub fn property_mut(&mut self) -> &mut T {
+        &mut self.property
+    }
+
+    /// Replaces the property, keeping the current value until the next one is generated.
+    pub fn set_property(&mut self, property: T) {
+        self.property = property;
+    }
+
+    pub fn interval(&self) -> &I {
+        &self.interval
+    }
+
+    /// Replaces the interval. The current interval is generated from it on the next tick, with the
+    /// rng used for the values, and [IntervalProperty::duration] keeps the old one until then.
+    /// The time already elapsed is kept, so shortening the interval past it generates a value on
+    /// that tick.
+    pub fn set_interval(&mut self, interval: I) {
+        self.interval = interval;
+        self.pending_interval = true;
+    }
+
+    /// The length of the current interval.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Time elapsed in the current interval.
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    /// Time left until the next value is generated.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Progress through the current interval, from 0.0 to 1.0, ie: for a progress bar.
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    pub fn mode(&self) -> TimerMode {
+        self.timer.mode()
+    }
+
+    /// [TimerMode::Once] generates a single value when the interval first finishes, until
+    /// [IntervalProperty::reset] or [IntervalProperty::force_regenerate] are used.
+    pub fn set_mode(&mut self, mode: TimerMode) {
+        self.timer.set_mode(mode);
+    }
+
+    /// Restarts the timer from zero, without generating a value.
+    pub fn reset(&mut self) {
+        self.timer.reset();
+        self.force = false;
+    }
+
+    /// Generates a new value on the next tick, regardless of the time left, and restarts the timer
+    /// from there.
+    pub fn force_regenerate(&mut self) {
+        self.force = true;
+    }
+
+    /// Eases from the previous value to each newly generated one over the interval, instead of
+    /// switching to it instantly. `None` goes back to switching instantly.
+    ///
+    /// Values are eased with [VariableProperty::interpolate], properties whose values can't be
+    /// interpolated keep switching instantly.
+    pub fn set_transition(&mut self, ease: Option<EaseFunction>) {
+        self.transition = ease;
+        if ease.is_none() {
+            // Lands on the value that was being eased to.
+            self.curr = self.to.take().or(self.curr.take());
+            self.from = None;
+        }
+    }
+
+    /// Same as [IntervalProperty::set_transition], ie: with [EaseFunction::Linear] or
+    /// [EaseFunction::SmoothStep].
+    pub fn with_transition(mut self, ease: EaseFunction) -> Self {
+        self.set_transition(Some(ease));
+        self
+    }
+}
+
+/// Negative intervals are treated as zero.
+fn interval_duration(secs: f32) -> Duration {
+    Duration::from_secs_f32(secs.max(0.0))
 }
 
 impl<T: VariableProperty + TypePath> IntervalProperty<T> {
     pub fn new(property: T, interval: f32) -> Self {
-        Self {
-            property,
-            timer: Timer::from_seconds(interval, TimerMode::Repeating),
-            curr: None,
-        }
+        let mut interval_property = Self::new_with_interval(property, Property::Static(interval));
+        // A static interval doesn't need an rng, so it's known from the start.
+        interval_property
+            .timer
+            .set_duration(interval_duration(interval));
+        interval_property.pending_interval = false;
+        interval_property
     }
 
     /// Explicitly set a starting value, which will be returned from [IntervalProperty::get_curr_value] until
     /// the internal timer finishes the first time.
     pub fn new_with_initial_value(property: T, interval: f32, init: T::Output) -> Self {
         Self {
-            property,
-            timer: Timer::from_seconds(interval, TimerMode::Repeating),
             curr: Some(init),
+            ..Self::new(property, interval)
         }
     }
 
     /// Explicitly set a starting value generated from the given Property, which will be returned from
     /// [IntervalProperty::get_curr_value] until the internal timer finishes the first time.
-    pub fn new_with_generated_inital_value(property: T, interval: f32) -> Self {
-        let curr = Some(property.get_value());
+    pub fn new_with_generated_inital_value(mut property: T, interval: f32) -> Self {
+        let init = property.next_value();
+        Self::new_with_initial_value(property, interval, init)
+    }
+}
+
+impl<T, I> Clone for IntervalProperty<T, I>
+where
+    T: VariableProperty + TypePath + Clone,
+    T::Output: Clone,
+    I: VariableProperty<Output = f32> + TypePath + Clone,
+{
+    fn clone(&self) -> Self {
         Self {
-            property,
-            timer: Timer::from_seconds(interval, TimerMode::Repeating),
-            curr,
+            property: self.property.clone(),
+            interval: self.interval.clone(),
+            timer: self.timer.clone(),
+            curr: self.curr.clone(),
+            transition: self.transition,
+            from: self.from.clone(),
+            to: self.to.clone(),
+            force: self.force,
+            generated: self.generated,
+            queued: self.queued,
+            pending_interval: self.pending_interval,
         }
     }
 }
 
 impl<T: VariableProperty + Default + TypePath> Default for IntervalProperty<T> {
     fn default() -> Self {
-        Self {
-            property: Default::default(),
-            timer: Timer::new(Duration::from_secs_f32(1.0), TimerMode::Repeating),
-            curr: None,
+        Self::new(Default::default(), 1.0)
+    }
+}
+
+/// Serializes as `{ property, interval, elapsed, curr, transition }`, with the interval and
+/// elapsed time in seconds, and the transition left out if there isn't one. A varying interval is generated again on the first tick after deserializing.
+#[cfg(feature = "serde")]
+impl<T, I> serde::Serialize for IntervalProperty<T, I>
+where
+    T: VariableProperty + TypePath + serde::Serialize,
+    T::Output: serde::Serialize,
+    I: VariableProperty<Output = f32> + TypePath + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        struct IntervalPropertyRepr<'a, T, I, O> {
+            property: &'a T,
+            interval: &'a I,
+            elapsed: f32,
+            curr: &'a Option<O>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            transition: Option<EaseFunction>,
+        }
+
+        IntervalPropertyRepr {
+            property: &self.property,
+            interval: &self.interval,
+            elapsed: self.timer.elapsed_secs(),
+            curr: &self.curr,
+            transition: self.transition,
         }
+        .serialize(serializer)
+    }
+}
+
+/// `elapsed`, `curr` and `transition` are optional and default to 0.0, no value and no transition.
+#[cfg(feature = "serde")]
+impl<'de, T, I> serde::Deserialize<'de> for IntervalProperty<T, I>
+where
+    T: VariableProperty + TypePath + serde::Deserialize<'de>,
+    T::Output: serde::Deserialize<'de>,
+    I: VariableProperty<Output = f32> + TypePath + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct IntervalPropertyRepr<T, I, O> {
+            property: T,
+            interval: I,
+            #[serde(default)]
+            elapsed: f32,
+            #[serde(default = "Option::default")]
+            curr: Option<O>,
+            #[serde(default)]
+            transition: Option<EaseFunction>,
+        }
+
+        let repr = IntervalPropertyRepr::<T, I, T::Output>::deserialize(deserializer)?;
+        let mut interval_property = Self::new_with_interval(repr.property, repr.interval);
+        interval_property
+            .timer
+            .set_elapsed(Duration::from_secs_f32(repr.elapsed));
+        interval_property.curr = repr.curr;
+        interval_property.transition = repr.transition;
+        Ok(interval_property)
     }
 }
 
@@ -80,37 +359,188 @@ pub trait IntervalPropertyComponent:
     AsMut<IntervalProperty<Self::Property>> + Component<Mutability = Mutable> + Sized
 {
     type Property: VariableProperty + TypePath;
-    type TargetComponent: Component<Mutability = Mutable>;
+    /// A mutable component, or a tuple of them with
+    /// [TargetComponents](crate::property_target::TargetComponents).
+    type TargetComponent: PropertyTargetData;
 
     fn update(
         new_value: &<Self::Property as VariableProperty>::Output,
-        target: &mut Self::TargetComponent,
+        target: &mut <Self::TargetComponent as PropertyTargetData>::Target<'_>,
     );
 
+    /// The entities updated with new values, the entity the property is on by default.
+    fn target(&self) -> PropertyTargetMode {
+        PropertyTargetMode::This
+    }
+
     /// The system that will tick the given component's IntervalProperty and run the defined update
-    /// function when a new value is generated.
+    /// function when a new value is generated, or on every tick while easing with a transition.
+    ///
+    /// New values are generated from the entity's [PropertyRngSeed] if it has one, otherwise from
+    /// the [PropertyRng] resource if it exists, otherwise from the thread-local rng. See
+    /// [PauseIntervalProperty] for how paused entities are handled, and
+    /// [IntervalPropertyComponent::system_with_events] to also send [PropertyRegenerated].
+    ///
+    /// Ticks with the default [Time] of the schedule it runs in, ie: `Time<Fixed>` in
+    /// `FixedUpdate`, scaled by the entity's [PropertyTimeScale] if it has one. See
+    /// [IntervalPropertyComponent::system_with_clock] to pick the clock.
     fn system(
-        mut query: Query<(
-            &mut Self,
-            &mut Self::TargetComponent,
-            Option<&PauseIntervalProperty<Self>>,
-        )>,
+        query: Query<IntervalPropertyQueryData<Self>>,
+        targets: PropertyTargetQuery<Self>,
         time: Res<Time>,
+        global_rng: Option<ResMut<PropertyRng>>,
     ) {
-        let delta = time.delta();
-        for (mut source, mut target, maybe_pause) in query.iter_mut() {
-            if let Some(new_value) =
-                AsMut::<IntervalProperty<Self::Property>>::as_mut(&mut *source).tick_value(delta)
-            {
-                if maybe_pause.is_none() {
-                    Self::update(new_value, target.as_mut());
-                }
+        Self::system_with_clock(query, targets, time, global_rng);
+    }
+
+    /// Same as [IntervalPropertyComponent::system], but ticks with the given clock, ie:
+    /// `Time<Real>` to keep going while `Time<Virtual>` is paused.
+    fn system_with_clock<C: Default + Send + Sync + 'static>(
+        query: Query<IntervalPropertyQueryData<Self>>,
+        targets: PropertyTargetQuery<Self>,
+        time: Res<Time<C>>,
+        global_rng: Option<ResMut<PropertyRng>>,
+    ) {
+        tick_components(query, targets, &time, global_rng, |_, _| {});
+    }
+
+    /// Same as [IntervalPropertyComponent::system], but each generated value also triggers a
+    /// [PropertyRegenerated] event on the entity, and is written as a message if they've been
+    /// added to the app. Needs the values to be cloneable to send them.
+    fn system_with_events(
+        query: Query<IntervalPropertyQueryData<Self>>,
+        targets: PropertyTargetQuery<Self>,
+        time: Res<Time>,
+        global_rng: Option<ResMut<PropertyRng>>,
+        commands: Commands,
+        messages: Option<MessageWriter<PropertyRegenerated<Self>>>,
+    ) where
+        PropertyOutput<Self>: Clone + Send + Sync,
+    {
+        Self::system_with_clock_and_events(query, targets, time, global_rng, commands, messages);
+    }
+
+    /// Same as [IntervalPropertyComponent::system_with_events], but ticks with the given clock.
+    fn system_with_clock_and_events<C: Default + Send + Sync + 'static>(
+        query: Query<IntervalPropertyQueryData<Self>>,
+        targets: PropertyTargetQuery<Self>,
+        time: Res<Time<C>>,
+        global_rng: Option<ResMut<PropertyRng>>,
+        mut commands: Commands,
+        mut messages: Option<MessageWriter<PropertyRegenerated<Self>>>,
+    ) where
+        PropertyOutput<Self>: Clone + Send + Sync,
+    {
+        tick_components(query, targets, &time, global_rng, |entity, value| {
+            if let Some(messages) = messages.as_mut() {
+                messages.write(PropertyRegenerated {
+                    entity,
+                    value: value.clone(),
+                });
+            }
+            commands.trigger(PropertyRegenerated::<Self> {
+                entity,
+                value: value.clone(),
+            });
+        });
+    }
+}
+
+/// Ticks every `T` and updates their targets, calling `regenerated` with each value generated
+/// while not paused.
+fn tick_components<T: IntervalPropertyComponent, C: Default + Send + Sync + 'static>(
+    mut query: Query<IntervalPropertyQueryData<T>>,
+    mut targets: PropertyTargetQuery<T>,
+    time: &Time<C>,
+    mut global_rng: Option<ResMut<PropertyRng>>,
+    mut regenerated: impl FnMut(Entity, &PropertyOutput<T>),
+) {
+    for (entity, mut source, maybe_pause, maybe_seed, maybe_scale) in query.iter_mut() {
+        let pause = maybe_pause.map(|pause| pause.mode);
+        if pause == Some(PauseMode::Freeze) {
+            continue;
+        }
+        let delta = match maybe_scale {
+            Some(scale) => time.delta().mul_f32(scale.0.max(0.0)),
+            None => time.delta(),
+        };
+        let mode = source.target();
+        let interval_property = AsMut::<IntervalProperty<T::Property>>::as_mut(&mut *source);
+        let changed = match (maybe_seed, global_rng.as_deref_mut()) {
+            (Some(mut seed), global_rng) => {
+                let rng = seed.rng(global_rng.map(|global_rng| global_rng.seed()));
+                interval_property.tick_value_with(delta, rng).is_some()
+            }
+            (None, Some(global_rng)) => interval_property
+                .tick_value_with(delta, global_rng)
+                .is_some(),
+            (None, None) => interval_property.tick_value(delta).is_some(),
+        };
+        let generated = interval_property.just_generated();
+        match pause {
+            Some(PauseMode::Queue) => {
+                interval_property.queued |= generated;
+                continue;
             }
+            Some(_) => continue,
+            None => {}
+        }
+        let queued = std::mem::take(&mut interval_property.queued);
+        if !changed && !queued {
+            continue;
+        }
+        let Some(curr) = interval_property.get_curr_value() else {
+            continue;
+        };
+        targets.update(entity, mode, curr);
+        if !generated && !queued {
+            continue;
+        }
+        if let Some(value) = interval_property.get_target_value() {
+            regenerated(entity, value);
         }
     }
 }
 
-#[derive(Default)]
+type IntervalPropertyQueryData<T> = (
+    Entity,
+    &'static mut T,
+    Option<&'static PauseIntervalProperty<T>>,
+    Option<&'static mut PropertyRngSeed>,
+    Option<&'static PropertyTimeScale>,
+);
+
+/// Scales the time [IntervalPropertyComponent::system] ticks the entity's interval properties
+/// with, ie: 2.0 to generate values twice as often. Negative scales are treated as zero.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct PropertyTimeScale(pub f32);
+
+/// No scaling.
+impl Default for PropertyTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Sent when the [IntervalPropertyComponent] `T` on `entity` generates a new value, from
+/// [IntervalPropertyComponent::system_with_events].
+///
+/// It's triggered as an [EntityEvent] for observers, and written as a [Message] for systems
+/// reading them in batches once added with `app.add_message::<PropertyRegenerated<T>>()`.
+#[derive(EntityEvent, Message)]
+pub struct PropertyRegenerated<T: IntervalPropertyComponent>
+where
+    PropertyOutput<T>: Send + Sync,
+{
+    pub entity: Entity,
+    pub value: PropertyOutput<T>,
+}
+
+/// The values generated by the [IntervalPropertyComponent] `T`.
+pub type PropertyOutput<T> =
+    <<T as IntervalPropertyComponent>::Property as VariableProperty>::Output;
+
 struct PhantomDataWrapper<T: IntervalPropertyComponent>(std::marker::PhantomData<T>);
 
 impl<T: IntervalPropertyComponent> PhantomDataWrapper<T> {
@@ -119,6 +549,12 @@ impl<T: IntervalPropertyComponent> PhantomDataWrapper<T> {
     }
 }
 
+impl<T: IntervalPropertyComponent> Default for PhantomDataWrapper<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: IntervalPropertyComponent> TypePath for PhantomDataWrapper<T> {
     fn type_path() -> &'static str {
         "bevy_variable_property::PhantomDataWrapper"
@@ -129,15 +565,77 @@ impl<T: IntervalPropertyComponent> TypePath for PhantomDataWrapper<T> {
     }
 }
 
-/// Marker component to pause the generation of new values from an IntervalPropertyComponent
-/// The timer will still tick and turn over, but [IntervalPropertyComponent::update] will not be
-/// called.
+/// How a [PauseIntervalProperty] pauses its entity.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PauseMode {
+    /// The timer keeps ticking and generating values, but they're dropped
+    #[default]
+    Skip,
+
+    /// The timer stops entirely, and picks up where it left off on resume
+    Freeze,
+
+    /// The timer keeps ticking, and the latest value generated while paused is applied on resume
+    Queue,
+}
+
+/// Component to pause the generation of new values from an IntervalPropertyComponent, according
+/// to its [PauseMode]. Paused entities don't have [IntervalPropertyComponent::update] called, or
+/// [PropertyRegenerated] sent, and resume once this is removed.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-pub struct PauseIntervalProperty<T: IntervalPropertyComponent>(PhantomDataWrapper<T>);
+pub struct PauseIntervalProperty<T: IntervalPropertyComponent> {
+    pub mode: PauseMode,
+    #[reflect(ignore)]
+    _marker: PhantomDataWrapper<T>,
+}
+
+impl<T: IntervalPropertyComponent> PauseIntervalProperty<T> {
+    pub fn new(mode: PauseMode) -> Self {
+        Self {
+            mode,
+            _marker: PhantomDataWrapper::new(),
+        }
+    }
+}
 
+/// Pauses with [PauseMode::Skip].
 impl<T: IntervalPropertyComponent> Default for PauseIntervalProperty<T> {
     fn default() -> Self {
-        Self(PhantomDataWrapper::<T>::new())
+        Self::new(PauseMode::Skip)
+    }
+}
+
+/// Commands to control an entity's [IntervalPropertyComponent] `T`, doing nothing if the entity
+/// doesn't have it.
+pub trait IntervalPropertyCommandsExt {
+    /// Restarts the timer, see [IntervalProperty::reset].
+    fn reset_interval_property<T: IntervalPropertyComponent>(&mut self) -> &mut Self;
+
+    /// Generates a new value the next time the system runs, see
+    /// [IntervalProperty::force_regenerate].
+    fn regenerate_interval_property<T: IntervalPropertyComponent>(&mut self) -> &mut Self;
+}
+
+impl IntervalPropertyCommandsExt for EntityCommands<'_> {
+    fn reset_interval_property<T: IntervalPropertyComponent>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            if let Some(mut source) = entity.get_mut::<T>() {
+                AsMut::<IntervalProperty<T::Property>>::as_mut(&mut *source).reset();
+            }
+        })
+    }
+
+    fn regenerate_interval_property<T: IntervalPropertyComponent>(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            if let Some(mut source) = entity.get_mut::<T>() {
+                AsMut::<IntervalProperty<T::Property>>::as_mut(&mut *source).force_regenerate();
+            }
+        })
     }
 }
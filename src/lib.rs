@@ -1,17 +1,43 @@
 #![doc = include_str!("../README.md")]
+// Lets the derive macros refer to this crate by name from within it.
+extern crate self as bevy_variable_property;
+
+pub mod combinators;
+pub mod dynamic_property;
 pub mod interval_property;
+pub mod noise_property;
+pub mod plugin;
+#[cfg(feature = "asset")]
+pub mod preset;
+pub mod prop_array;
+pub mod prop_distribution;
 pub mod prop_rand;
 pub mod prop_range;
+pub mod prop_sequence;
+pub mod prop_shape;
+pub mod prop_weighted;
+pub mod property_binding;
+pub mod property_error;
+pub mod property_target;
+pub mod rng;
 pub mod variable_property;
 
 use bevy_math::*;
 use bevy_reflect::{Reflect, TypePath};
-use rand::{seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, RngCore};
 
 use std::ops::{Range, RangeInclusive};
 
+#[doc(hidden)]
+pub use rand as __rand;
+
+use crate::prop_distribution::PropDistribution;
 use crate::prop_rand::PropRand;
 use crate::prop_range::PropRange;
+use crate::prop_sequence::PropSequence;
+use crate::prop_shape::PropShape;
+use crate::prop_weighted::PropWeighted;
+use crate::property_error::PropertyError;
 
 use crate::variable_property::VariableProperty;
 
@@ -30,8 +56,37 @@ pub enum Property<T> {
     /// Produces a randomly selected value from the given list
     RandomChoice(Vec<T>),
 
+    /// Produces a randomly selected value from the given list, where each value is picked with a
+    /// probability proportional to its weight
+    WeightedChoice(PropWeighted<T>),
+
+    /// Steps through the given list according to its
+    /// [SequenceMode](prop_sequence::SequenceMode) each time
+    /// [next_value](VariableProperty::next_value) is used, ie: by an
+    /// [IntervalProperty](interval_property::IntervalProperty).
+    ///
+    /// Only the `next_value` methods advance the sequence, [get_value](VariableProperty::get_value)
+    /// returns the current element, see [PropSequence::current].
+    Sequence(PropSequence<T>),
+
     /// Produces a completely random value
     Random,
+
+    /// Produces a random value within the given range, shaped by the given distribution
+    Distribution {
+        range: PropRange<T>,
+        distribution: PropDistribution,
+    },
+
+    /// Produces a uniformly random point inside the given shape, only supported by Vec2 for 2D
+    /// shapes and Vec3 for any shape. Other combinations panic when sampled, see
+    /// [Property::validate].
+    InShape(PropShape),
+
+    /// Produces a uniformly random point on the boundary of the given shape, only supported by
+    /// Vec2 for 2D shapes and Vec3 for any shape. Other combinations panic when sampled, see
+    /// [Property::validate].
+    OnShape(PropShape),
 }
 
 impl<T> VariableProperty for Property<T>
@@ -41,16 +96,129 @@ where
     type Output = T;
     /// Gets a value based on the parameters of the Property
     /// See [Property] for more information.
-    fn get_value(&self) -> T {
+    ///
+    /// # Panics
+    /// If the property isn't valid, ie: a reversed or empty range, no choices to pick from, or a
+    /// shape the type can't sample points from. Use [Property::try_get_value] for properties
+    /// that haven't been checked with [Property::validate].
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> T {
         match self {
             Property::Static(v) => v.clone(),
-            Property::RandomRange(range) => {
-                <T as PropRand>::gen_range(&mut thread_rng(), range.clone())
+            Property::RandomRange(range) => <T as PropRand>::gen_range(rng, range.clone()),
+            Property::RandomChoice(choices) => choices.choose(rng).unwrap().clone(),
+            Property::WeightedChoice(choices) => choices.choose(rng).unwrap().clone(),
+            Property::Sequence(sequence) => sequence.current().unwrap().clone(),
+            Property::Random => T::gen(rng),
+            Property::Distribution {
+                range,
+                distribution,
+            } => T::gen_distribution(rng, range.clone(), distribution),
+            Property::InShape(shape) => shape_value(T::gen_shape(rng, shape, false)),
+            Property::OnShape(shape) => shape_value(T::gen_shape(rng, shape, true)),
+        }
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> T {
+        match self {
+            Property::Sequence(sequence) => sequence.next(rng).unwrap().clone(),
+            _ => self.get_value_with(rng),
+        }
+    }
+
+    /// See [PropRand::interpolate].
+    fn interpolate(from: &T, to: &T, t: f32) -> Option<T> {
+        T::interpolate(from, to, t)
+    }
+}
+
+fn shape_value<T>(value: Result<T, PropertyError>) -> T {
+    value.unwrap_or_else(|err| panic!("cannot sample {}: {err}", std::any::type_name::<T>()))
+}
+
+impl<T> Property<T> {
+    /// Shorthand for [Property::InShape]. The shape isn't checked against `T` until the property
+    /// is validated or sampled.
+    pub fn in_shape(shape: impl Into<PropShape>) -> Self {
+        Property::InShape(shape.into())
+    }
+
+    /// Shorthand for [Property::OnShape]. The shape isn't checked against `T` until the property
+    /// is validated or sampled.
+    pub fn on_shape(shape: impl Into<PropShape>) -> Self {
+        Property::OnShape(shape.into())
+    }
+}
+
+impl<T: PropRand + Clone> Property<T> {
+    /// Checks that values can be generated from the property, so that properties loaded from
+    /// files can be rejected instead of panicking on [VariableProperty::get_value].
+    ///
+    /// Shapes are checked against the type, see [PropRand::gen_shape], and distributions with
+    /// [PropDistribution::validate].
+    pub fn validate(&self) -> Result<(), PropertyError> {
+        match self {
+            Property::InShape(shape) | Property::OnShape(shape) => T::validate_shape(shape),
+            Property::RandomRange(range) => range.validate(),
+            Property::Distribution {
+                range,
+                distribution,
+            } => {
+                range.validate()?;
+                distribution.validate()?;
+                // A dry run, as only types implementing from_units support other distributions
+                let uniform = matches!(distribution, PropDistribution::Uniform);
+                if !uniform && T::from_units(range.clone(), &mut || 0.0).is_none() {
+                    return Err(PropertyError::UnsupportedDistribution);
+                }
+                Ok(())
             }
-            Property::RandomChoice(choices) => choices.choose(&mut thread_rng()).unwrap().clone(),
-            Property::Random => T::gen(&mut thread_rng()),
+            Property::RandomChoice(choices) if choices.is_empty() => {
+                Err(PropertyError::EmptyChoices)
+            }
+            Property::WeightedChoice(choices) if choices.choices().is_empty() => {
+                Err(PropertyError::EmptyChoices)
+            }
+            Property::WeightedChoice(choices)
+                if !choices.total_weight().is_finite()
+                    || choices
+                        .choices()
+                        .iter()
+                        .any(|(_, weight)| !weight.is_finite()) =>
+            {
+                Err(PropertyError::NonFiniteWeight)
+            }
+            Property::WeightedChoice(choices) if choices.total_weight() <= 0.0 => {
+                Err(PropertyError::ZeroWeights)
+            }
+            Property::Sequence(sequence) if sequence.choices().is_empty() => {
+                Err(PropertyError::EmptyChoices)
+            }
+            _ => Ok(()),
         }
     }
+
+    /// Swaps the bounds of a reversed range, then checks the property with
+    /// [Property::validate]. Opt-in, as reversed bounds are often a mistake worth reporting.
+    pub fn fix_reversed_bounds(&mut self) -> Result<(), PropertyError> {
+        if let Property::RandomRange(range) | Property::Distribution { range, .. } = self {
+            range.fix_reversed_bounds();
+        }
+        self.validate()
+    }
+}
+
+impl<T: PropRand + Clone + TypePath> Property<T> {
+    /// Same as [VariableProperty::get_value], but returns an error instead of panicking if the
+    /// property isn't valid, see [Property::validate].
+    pub fn try_get_value(&self) -> Result<T, PropertyError> {
+        self.try_get_value_with(&mut rand::thread_rng())
+    }
+
+    /// Same as [Property::try_get_value], using the given rng.
+    pub fn try_get_value_with(&self, rng: &mut dyn RngCore) -> Result<T, PropertyError> {
+        self.validate()?;
+        Ok(self.get_value_with(rng))
+    }
 }
 
 /// Provides `Static(T::default())`
@@ -136,14 +304,27 @@ macro_rules! prop_from_impl_many {
 
 prop_from_impl_many!(
     usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, Vec2, Vec3, Vec4,
-    UVec2, UVec3, UVec4, IVec2, IVec3, IVec4, DVec2, DVec3, DVec4,
+    UVec2, UVec3, UVec4, IVec2, IVec3, IVec4, DVec2, DVec3, DVec4, Quat, Rot2, Dir2, Dir3, Mat2,
+    Mat3, Mat4,
 );
 
+#[cfg(feature = "color")]
+mod color_from_impls {
+    use super::*;
+    use bevy_color::{
+        Color, Hsla, Hsva, Hwba, Laba, Lcha, LinearRgba, Oklaba, Oklcha, Srgba, Xyza,
+    };
+
+    prop_from_impl_many!(
+        Color, Srgba, LinearRgba, Hsla, Hsva, Hwba, Laba, Lcha, Oklaba, Oklcha, Xyza,
+    );
+}
+
 impl<T, const N: usize> From<Range<[T; N]>> for Property<[T; N]> {
     fn from(v: Range<[T; N]>) -> Self {
         Self::RandomRange(PropRange {
-            start: v.start.into(),
-            end: v.end.into(),
+            start: v.start,
+            end: v.end,
             inclusive: false,
         })
     }
@@ -161,13 +342,13 @@ impl<T: Clone, const N: usize> From<RangeInclusive<[T; N]>> for Property<[T; N]>
 
 impl<T, const N: usize> From<Vec<[T; N]>> for Property<[T; N]> {
     fn from(v: Vec<[T; N]>) -> Self {
-        Property::RandomChoice(v.into_iter().map(|x| x.into()).collect())
+        Property::RandomChoice(v)
     }
 }
 
 impl<T: Clone, const N: usize> From<&[[T; N]]> for Property<[T; N]> {
     fn from(v: &[[T; N]]) -> Self {
-        Property::RandomChoice(v.into_iter().cloned().collect())
+        Property::RandomChoice(v.to_vec())
     }
 }
 
@@ -177,16 +358,192 @@ impl<T, const N: usize, const M: usize> From<[[T; N]; M]> for Property<[T; N]> {
     }
 }
 
+impl<T> From<PropWeighted<T>> for Property<T> {
+    fn from(v: PropWeighted<T>) -> Self {
+        Property::WeightedChoice(v)
+    }
+}
+
+impl<T> From<PropSequence<T>> for Property<T> {
+    fn from(v: PropSequence<T>) -> Self {
+        Property::Sequence(v)
+    }
+}
+
+impl<T> From<Vec<(T, f32)>> for Property<T> {
+    fn from(v: Vec<(T, f32)>) -> Self {
+        Property::WeightedChoice(v.into())
+    }
+}
+
+impl<T: Clone> From<&[(T, f32)]> for Property<T> {
+    fn from(v: &[(T, f32)]) -> Self {
+        Property::WeightedChoice(v.to_vec().into())
+    }
+}
+
+impl<T, const N: usize> From<[(T, f32); N]> for Property<T> {
+    fn from(v: [(T, f32); N]) -> Self {
+        Property::WeightedChoice(Vec::from(v).into())
+    }
+}
+
+/// Serializes to the most compact form of each variant:
+/// `"random"` for [Property::Random], `{ range: [start, end], inclusive: bool }` for
+/// [Property::RandomRange], the same with a `distribution` field for [Property::Distribution],
+/// `{ weighted: [[value, weight], ...] }` for [Property::WeightedChoice],
+/// `{ sequence: [value, ...], mode: mode }` for [Property::Sequence],
+/// `{ in_shape: shape }` and `{ on_shape: shape }` for the shapes, a list of values for
+/// [Property::RandomChoice] and the value itself for [Property::Static].
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Property<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        #[serde(untagged)]
+        enum PropertyRepr<'a, T> {
+            Random(PropertyReprRandom),
+            Distribution {
+                #[serde(flatten)]
+                range: &'a PropRange<T>,
+                distribution: &'a PropDistribution,
+            },
+            Range(&'a PropRange<T>),
+            Weighted {
+                weighted: &'a PropWeighted<T>,
+            },
+            Sequence {
+                sequence: &'a [T],
+                mode: prop_sequence::SequenceMode,
+            },
+            InShape {
+                in_shape: &'a PropShape,
+            },
+            OnShape {
+                on_shape: &'a PropShape,
+            },
+            Static(&'a T),
+            Choice(&'a [T]),
+        }
+
+        match self {
+            Property::Static(v) => PropertyRepr::Static(v),
+            Property::RandomRange(range) => PropertyRepr::Range(range),
+            Property::RandomChoice(choices) => PropertyRepr::Choice(choices),
+            Property::WeightedChoice(choices) => PropertyRepr::Weighted { weighted: choices },
+            Property::Sequence(sequence) => PropertyRepr::Sequence {
+                sequence: sequence.choices(),
+                mode: sequence.mode(),
+            },
+            Property::Random => PropertyRepr::Random(PropertyReprRandom::Random),
+            Property::InShape(in_shape) => PropertyRepr::InShape { in_shape },
+            Property::OnShape(on_shape) => PropertyRepr::OnShape { on_shape },
+            Property::Distribution {
+                range,
+                distribution,
+            } => PropertyRepr::Distribution {
+                range,
+                distribution,
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes from the forms described on the [serde::Serialize] impl. A list is read as a
+/// [Property::Static] value when it is a valid `T` and as a [Property::RandomChoice] otherwise.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Property<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum PropertyRepr<T> {
+            Random(PropertyReprRandom),
+            Distribution {
+                #[serde(flatten)]
+                range: PropRange<T>,
+                distribution: PropDistribution,
+            },
+            Range(PropRange<T>),
+            Weighted {
+                weighted: PropWeighted<T>,
+            },
+            Sequence {
+                sequence: Vec<T>,
+                #[serde(default)]
+                mode: prop_sequence::SequenceMode,
+            },
+            InShape {
+                in_shape: PropShape,
+            },
+            OnShape {
+                on_shape: PropShape,
+            },
+            Static(T),
+            Choice(Vec<T>),
+        }
+
+        Ok(match PropertyRepr::deserialize(deserializer)? {
+            PropertyRepr::Random(_) => Property::Random,
+            PropertyRepr::Distribution {
+                range,
+                distribution,
+            } => Property::Distribution {
+                range,
+                distribution,
+            },
+            PropertyRepr::Range(range) => Property::RandomRange(range),
+            PropertyRepr::Weighted { weighted } => Property::WeightedChoice(weighted),
+            PropertyRepr::Sequence { sequence, mode } => {
+                Property::Sequence(PropSequence::new(sequence, mode))
+            }
+            PropertyRepr::InShape { in_shape } => Property::InShape(in_shape),
+            PropertyRepr::OnShape { on_shape } => Property::OnShape(on_shape),
+            PropertyRepr::Static(v) => Property::Static(v),
+            PropertyRepr::Choice(choices) => Property::RandomChoice(choices),
+        })
+    }
+}
+
+/// The `"random"` string used for [Property::Random].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PropertyReprRandom {
+    Random,
+}
+
 pub mod prelude {
+    pub use crate::prop_rand::PropRand;
     pub use crate::{
-        interval_property::IntervalProperty, prop_range::PropRange,
-        variable_property::VariableProperty, Property,
+        dynamic_property::{DynamicProperty, DynamicValue, ReflectVariableProperty},
+        interval_property::{
+            IntervalProperty, IntervalPropertyCommandsExt, PauseIntervalProperty, PauseMode,
+            PropertyRegenerated, PropertyTimeScale,
+        },
+        noise_property::{NoiseKind, NoiseProperty},
+        plugin::{IntervalPropertyAppExt, IntervalPropertySystems, VariablePropertyPlugin},
+        prop_array::PropArray,
+        prop_distribution::PropDistribution,
+        prop_range::PropRange,
+        prop_sequence::{PropSequence, SequenceMode},
+        prop_shape::PropShape,
+        prop_weighted::PropWeighted,
+        property_binding::PropertyBinding,
+        property_error::PropertyError,
+        property_target::{
+            PropertyTargetMode, PropertyTargetOf, PropertyTargets, TargetComponents,
+        },
+        rng::{PropertyRng, PropertyRngSeed},
+        variable_property::VariableProperty,
+        Property,
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::interval_property::IntervalPropertyComponent;
+    use bevy_ecs::component::Component;
     #[test]
     fn range_generation() {
         let ranges = (2.5..5.0, -10.0..0.0, 0.0..1.0);
@@ -195,7 +552,7 @@ mod tests {
             ranges.1.clone().into(),
             ranges.2.clone().into(),
         );
-        let (x, y, z) = vec3_generator.get_value().into();
+        let (x, y, z) = vec3_generator.get_value();
         assert!(
             ranges.0.contains(&x),
             "{} was not in the range of ({}..{})",
@@ -254,9 +611,1650 @@ mod tests {
         p.get_value();
     }
 
+    #[test]
+    fn validation() {
+        use prop_array::PropArray;
+        use property_error::PropertyError;
+
+        assert_eq!(
+            Property::from(10.0..1.0).validate(),
+            Err(PropertyError::ReversedBounds)
+        );
+        assert_eq!(
+            Property::from([0.0, 10.0]..[1.0, 5.0]).try_get_value(),
+            Err(PropertyError::ReversedBounds)
+        );
+        assert_eq!(
+            Property::from(1..1).validate(),
+            Err(PropertyError::EmptyRange)
+        );
+        assert_eq!(Property::from(1..=1).try_get_value(), Ok(1));
+        assert_eq!(
+            Property::from(f32::NAN..1.0).validate(),
+            Err(PropertyError::NonFiniteBound)
+        );
+        assert_eq!(
+            Property::<f32>::RandomChoice(vec![]).try_get_value(),
+            Err(PropertyError::EmptyChoices)
+        );
+        assert_eq!(
+            Property::WeightedChoice(vec![(1, 0.0)].into()).validate(),
+            Err(PropertyError::ZeroWeights)
+        );
+        assert_eq!(
+            Property::RandomRange(PropRange::from((0.0, 5.0)..(1.0, 2.0))).validate(),
+            Err(PropertyError::ReversedBounds)
+        );
+
+        let mut p = Property::from(Vec2::new(0.0, 10.0)..Vec2::new(1.0, 5.0));
+        assert_eq!(p.fix_reversed_bounds(), Ok(()));
+        let v = p.get_value();
+        assert!((0.0..1.0).contains(&v.x) && (5.0..10.0).contains(&v.y));
+        let mut p = Property::from(f32::NAN..1.0);
+        assert_eq!(p.fix_reversed_bounds(), Err(PropertyError::NonFiniteBound));
+
+        let mut p = Property::from(PropArray([0.0f32, 10.0])..PropArray([1.0, 5.0]));
+        assert_eq!(p.validate(), Err(PropertyError::ReversedBounds));
+        assert_eq!(p.fix_reversed_bounds(), Ok(()));
+        let v = p.get_value();
+        assert!((0.0..1.0).contains(&v.0[0]) && (5.0..10.0).contains(&v.0[1]));
+        let p: Property<Vec2> = Property::in_shape(bevy_math::primitives::Sphere::new(1.0));
+        assert_eq!(p.validate(), Err(PropertyError::UnsupportedShape));
+        let p = Property::WeightedChoice(vec![(1, 1.0), (2, f32::INFINITY)].into());
+        assert_eq!(p.try_get_value(), Err(PropertyError::NonFiniteWeight));
+        let p = Property::WeightedChoice(vec![(1, f32::MAX), (2, f32::MAX)].into());
+        assert_eq!(p.validate(), Err(PropertyError::NonFiniteWeight));
+        assert_eq!(
+            Property::from(0.0..f32::INFINITY).try_get_value(),
+            Err(PropertyError::NonFiniteBound)
+        );
+        assert_eq!(
+            Property::from(-f64::MAX..f64::MAX).validate(),
+            Err(PropertyError::NonFiniteBound)
+        );
+        let p = Property::from(Quat::IDENTITY..Quat::from_xyzw(f32::NAN, 0.0, 0.0, 1.0));
+        assert_eq!(p.validate(), Err(PropertyError::NonFiniteBound));
+    }
+
+    #[test]
+    fn distribution_validation() {
+        use property_error::PropertyError;
+
+        let distribution = |distribution| Property::Distribution {
+            range: (0.0..1.0).into(),
+            distribution,
+        };
+        for (d, err) in [
+            (
+                PropDistribution::Normal {
+                    mean: f32::NAN,
+                    std_dev: 0.1,
+                },
+                PropertyError::NonFiniteParameter,
+            ),
+            (
+                PropDistribution::Normal {
+                    mean: 0.5,
+                    std_dev: 0.0,
+                },
+                PropertyError::NonPositiveStdDev,
+            ),
+            (
+                PropDistribution::Triangular { mode: 1.5 },
+                PropertyError::ModeOutOfRange,
+            ),
+            (
+                PropDistribution::Exponential {
+                    rate: f32::INFINITY,
+                },
+                PropertyError::NonFiniteParameter,
+            ),
+            (PropDistribution::Cdf(vec![]), PropertyError::EmptyCdf),
+            (
+                PropDistribution::Cdf(vec![0.0, 0.8, 0.5, 1.0]),
+                PropertyError::DecreasingCdf,
+            ),
+        ] {
+            assert_eq!(distribution(d.clone()).validate(), Err(err), "{d:?}");
+            assert_eq!(d.validate(), Err(err));
+        }
+        let p = distribution(PropDistribution::Exponential { rate: -2.0 });
+        assert_eq!(p.validate(), Ok(()));
+        // The range is checked before the distribution
+        let p = Property::Distribution {
+            range: (1.0..0.0).into(),
+            distribution: PropDistribution::Triangular { mode: 2.0 },
+        };
+        assert_eq!(p.validate(), Err(PropertyError::ReversedBounds));
+
+        // Doesn't implement PropRand::from_units, so only uniform distributions work
+        #[derive(Clone, Debug, PartialEq, bevy_reflect::TypePath)]
+        struct Meters(f32);
+        impl PropRand for Meters {
+            fn gen<R: rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self(f32::gen(rng))
+            }
+            fn gen_range<R: rand::RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                let range = PropRange::new(range.start.0, range.end.0, range.inclusive);
+                Self(f32::gen_range(rng, range))
+            }
+        }
+        let mut p = Property::Distribution {
+            range: (Meters(0.0)..Meters(1.0)).into(),
+            distribution: PropDistribution::Uniform,
+        };
+        assert_eq!(p.validate(), Ok(()));
+        if let Property::Distribution { distribution, .. } = &mut p {
+            *distribution = PropDistribution::Triangular { mode: 0.5 };
+        }
+        assert_eq!(
+            p.try_get_value(),
+            Err(PropertyError::UnsupportedDistribution)
+        );
+    }
+
     #[test]
     fn tuples() {
         let p = Property::Static((1.0, 5.0));
         p.get_value();
     }
+
+    #[test]
+    fn seeded_generation() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let p: (Property<f32>, Property<[u8; 3]>, Property<Vec2>) = (
+            (0.0..100.0).into(),
+            vec![[1, 2, 3], [4, 5, 6], [7, 8, 9]].into(),
+            Property::Random,
+        );
+        let mut rng_a = StdRng::seed_from_u64(42);
+        let mut rng_b = StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            assert_eq!(p.get_value_with(&mut rng_a), p.get_value_with(&mut rng_b));
+        }
+    }
+
+    #[test]
+    fn boxed_properties() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut properties: Vec<Box<dyn VariableProperty<Output = f32>>> = vec![
+            Box::new(Property::from(0.0..1.0)),
+            Box::new(Property::Sequence(vec![2.0, 3.0].into())),
+            Box::new(Property::Static(4.0).map(|v| v * 2.0)),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+        let values: Vec<f32> = properties
+            .iter_mut()
+            .map(|property| property.next_value_with(&mut rng))
+            .collect();
+        assert!((0.0..1.0).contains(&values[0]));
+        assert_eq!(values[1..], [2.0, 8.0]);
+        assert_eq!(properties[1].next_value(), 3.0);
+    }
+
+    #[test]
+    fn rng_seed_follows_global_seed() {
+        use rand::RngCore;
+        use rng::PropertyRngSeed;
+
+        let fresh = |global_seed| PropertyRngSeed::new(7).rng(global_seed).next_u64();
+        let mut seed = PropertyRngSeed::new(7);
+        assert_eq!(seed.rng(None).next_u64(), fresh(None));
+        // A global rng inserted later restarts the stream from the combined seed.
+        assert_eq!(seed.rng(Some(1)).next_u64(), fresh(Some(1)));
+        assert_ne!(seed.rng(Some(1)).next_u64(), fresh(Some(1)));
+        assert_eq!(seed.rng(Some(2)).next_u64(), fresh(Some(2)));
+    }
+
+    #[test]
+    fn weighted_choice() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let p: Property<u8> = vec![(0, 1.0), (1, 0.0), (2, 3.0)].into();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut counts = [0; 3];
+        for _ in 0..4000 {
+            counts[p.get_value_with(&mut rng) as usize] += 1;
+        }
+        assert_eq!(counts[1], 0, "a zero weight choice was selected");
+        assert!(
+            (2500..3500).contains(&counts[2]),
+            "{:?} does not reflect the 1:3 weighting",
+            counts
+        );
+    }
+
+    #[test]
+    fn weighted_choice_reflection() {
+        use bevy_reflect::{FromReflect, GetPath, PartialReflect, ReflectPath};
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut weighted = PropWeighted::new(vec![(0u8, 1.0), (1, 0.0)]);
+
+        // Weights edited in place, ie: by an inspector, apply to the next choice.
+        *weighted.path_mut::<f32>("choices[0].1").unwrap() = 0.0;
+        *weighted.path_mut::<f32>("choices[1].1").unwrap() = 1.0;
+        assert_eq!(weighted.total_weight(), 1.0);
+        assert!((0..20).all(|_| weighted.choose(&mut rng) == Some(&1)));
+
+        // So do weights from a reflected value, ie: a scene file.
+        let mut reflected = weighted.to_dynamic();
+        let mut set_weight = |path: &str, weight: f32| {
+            let field = path.reflect_element_mut(reflected.as_mut()).unwrap();
+            *field.try_downcast_mut::<f32>().unwrap() = weight;
+        };
+        set_weight("choices[0].1", 2.0);
+        set_weight("choices[1].1", 0.0);
+        let weighted = PropWeighted::<u8>::from_reflect(reflected.as_ref()).unwrap();
+        assert_eq!(weighted.choose(&mut rng), Some(&0));
+        let empty = PropWeighted::<u8>::from_reflect(&PropWeighted::<u8>::new(vec![])).unwrap();
+        assert_eq!(empty.choose(&mut rng), None);
+    }
+
+    #[test]
+    fn sequences() {
+        use prop_sequence::SequenceMode;
+
+        let take = |mode, n| {
+            let mut p = Property::Sequence(PropSequence::new(vec![0, 1, 2], mode));
+            (0..n).map(|_| p.next_value()).collect::<Vec<_>>()
+        };
+        assert_eq!(take(SequenceMode::Sequence, 7), [0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(take(SequenceMode::PingPong, 7), [0, 1, 2, 1, 0, 1, 2]);
+
+        let bag = take(SequenceMode::ShuffleBag, 9);
+        for round in bag.chunks(3) {
+            let mut round = round.to_vec();
+            round.sort();
+            assert_eq!(round, [0, 1, 2]);
+        }
+        let no_repeat = take(SequenceMode::NoRepeat, 100);
+        assert!(no_repeat.windows(2).all(|w| w[0] != w[1]));
+
+        let mut interval = interval_property::IntervalProperty::new(
+            Property::from(PropSequence::from(vec![1, 2])),
+            1.0,
+        );
+        let mut tick = || {
+            *interval
+                .tick_value(core::time::Duration::from_secs(1))
+                .unwrap()
+        };
+        assert_eq!([tick(), tick(), tick()], [1, 2, 1]);
+
+        // get_value returns the current element without advancing
+        let mut p = Property::Sequence(PropSequence::from(vec![0, 1, 2]));
+        assert_eq!([p.get_value(), p.get_value()], [0, 0]);
+        assert_eq!(p.next_value(), 0);
+        assert_eq!(p.next_value(), 1);
+        assert_eq!([p.get_value(), p.get_value()], [1, 1]);
+        assert_eq!(p.next_value(), 2);
+    }
+
+    #[test]
+    fn interval_transitions() {
+        use bevy_math::curve::EaseFunction;
+        use core::time::Duration;
+        use interval_property::IntervalProperty;
+
+        let sequence = Property::from(PropSequence::from(vec![10.0f32, 20.0]));
+        let mut p = IntervalProperty::new_with_initial_value(sequence, 1.0, 0.0)
+            .with_transition(EaseFunction::Linear);
+        let mut tick = |secs| p.tick_value(Duration::from_secs_f32(secs)).copied();
+        assert_eq!(tick(0.5), None);
+        assert_eq!(tick(0.5), Some(0.0));
+        assert_eq!(tick(0.25), Some(2.5));
+        assert_eq!(tick(0.75), Some(10.0));
+        assert_eq!(tick(0.5), Some(15.0));
+
+        // The transition survives a reflection round trip.
+        use bevy_reflect::FromReflect;
+        let mut cloned = IntervalProperty::<Property<f32>>::from_reflect(&p).unwrap();
+        assert_eq!(
+            cloned.tick_value(Duration::from_secs_f32(0.25)),
+            Some(&17.5)
+        );
+
+        p.set_transition(None);
+        assert_eq!(p.get_curr_value(), Some(&20.0));
+        assert_eq!(p.tick_value(Duration::from_secs_f32(0.25)), None);
+    }
+
+    #[test]
+    fn variable_intervals() {
+        use core::time::Duration;
+        use interval_property::IntervalProperty;
+
+        let intervals = Property::from(PropSequence::from(vec![1.0, 2.0]));
+        let mut p = IntervalProperty::new_with_interval(Property::Static(1u8), intervals);
+        let mut tick = || p.tick_value(Duration::from_secs(1)).is_some();
+        assert_eq!([tick(), tick(), tick(), tick()], [true, false, true, true]);
+
+        let mut p =
+            IntervalProperty::new_with_interval(Property::Static(1u8), Property::from(0.5..2.0));
+        let mut since_last = 0;
+        for _ in 0..1000 {
+            since_last += 1;
+            if p.tick_value(Duration::from_millis(10)).is_some() {
+                assert!((49..=201).contains(&since_last));
+                since_last = 0;
+            }
+        }
+
+        // The first interval is drawn on the first tick, from the rng given to it.
+        use rand::{rngs::StdRng, SeedableRng};
+        let intervals = Property::from(0.5..2.0f32);
+        let mut p = IntervalProperty::new_with_interval(Property::Static(1u8), intervals.clone());
+        assert_eq!(p.duration(), Duration::ZERO);
+        assert!(p
+            .tick_value_with(Duration::ZERO, &mut StdRng::seed_from_u64(3))
+            .is_none());
+        let expected = intervals.get_value_with(&mut StdRng::seed_from_u64(3));
+        assert_eq!(p.duration(), Duration::from_secs_f32(expected));
+    }
+
+    #[test]
+    fn interval_timer_control() {
+        use bevy_time::TimerMode;
+        use core::time::Duration;
+        use interval_property::IntervalProperty;
+
+        let mut p = IntervalProperty::new(Property::Static(1u8), 2.0);
+        assert!(p.tick_value(Duration::from_millis(500)).is_none());
+        assert_eq!(p.fraction(), 0.25);
+        assert_eq!(p.remaining(), Duration::from_millis(1500));
+
+        // Halving the interval keeps the time elapsed, and takes effect on the next tick.
+        p.set_interval(Property::Static(1.0));
+        assert_eq!(p.duration(), Duration::from_secs(2));
+        assert_eq!(p.tick_value(Duration::from_millis(500)), Some(&1));
+        assert_eq!(p.duration(), Duration::from_secs(1));
+
+        p.set_property(Property::Static(2));
+        *p.property_mut() = Property::Static(3);
+        p.set_mode(TimerMode::Once);
+        p.reset();
+        assert_eq!(p.elapsed(), Duration::ZERO);
+        assert_eq!(p.tick_value(Duration::from_secs(1)), Some(&3));
+        assert!(p.tick_value(Duration::from_secs(1)).is_none());
+        p.force_regenerate();
+        assert_eq!(p.tick_value(Duration::ZERO), Some(&3));
+        assert!(p.property().get_value() == 3 && p.interval().get_value() == 1.0);
+    }
+
+    #[test]
+    fn noise_generation() {
+        use noise_property::{NoiseKind, NoiseProperty};
+
+        for kind in [NoiseKind::Value, NoiseKind::Perlin, NoiseKind::Simplex] {
+            let mut noise = NoiseProperty::new(Vec3::splat(-1.0)..=Vec3::splat(1.0), kind);
+            noise.octaves = 3;
+            let mut prev = noise.sample(0.0);
+            let (mut min, mut max) = (prev, prev);
+            for _ in 0..500 {
+                let v = noise.tick(core::time::Duration::from_millis(10));
+                assert!(v.abs().cmple(Vec3::ONE).all());
+                assert!((v - prev).abs().max_element() < 0.5, "{kind:?} jumped");
+                prev = v;
+                (min, max) = (min.min(v), max.max(v));
+            }
+            assert!((max - min).min_element() > 0.3, "{kind:?} barely changed");
+            assert!(prev.x != prev.y && prev.y != prev.z);
+            assert_eq!(noise.get_value(), noise.sample(noise.elapsed));
+        }
+
+        let noise = NoiseProperty::new(0u8..10, NoiseKind::Simplex);
+        assert!((0..1000).all(|i| noise.sample(i as f32 * 0.1) < 10));
+        let (a, b) = (noise.sample_at(Vec3::ONE), noise.sample_at(Vec3::ONE));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn noise_in_interval_property() {
+        use core::time::Duration;
+        use interval_property::IntervalProperty;
+        use noise_property::{NoiseKind, NoiseProperty};
+
+        let noise = NoiseProperty::new(-1.0f32..=1.0, NoiseKind::Perlin);
+        let mut p = IntervalProperty::new(noise, 1.0);
+        let values: Vec<f32> = (0..20)
+            .filter_map(|_| p.tick_value(Duration::from_secs(1)).copied())
+            .collect();
+        assert_eq!(values.len(), 20);
+        assert!(values.windows(2).all(|w| w[0] != w[1]), "noise didn't move");
+        assert!(values.windows(2).all(|w| (w[0] - w[1]).abs() < 0.5));
+        assert!((p.property().elapsed - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot map")]
+    fn noise_without_from_units() {
+        use noise_property::{NoiseKind, NoiseProperty};
+
+        // Doesn't implement PropRand::from_units
+        #[derive(Clone, Debug, PartialEq, bevy_reflect::TypePath)]
+        struct Meters(f32);
+        impl PropRand for Meters {
+            fn gen<R: rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self(f32::gen(rng))
+            }
+            fn gen_range<R: rand::RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                let range = PropRange::new(range.start.0, range.end.0, range.inclusive);
+                Self(f32::gen_range(rng, range))
+            }
+        }
+
+        let noise = NoiseProperty::new(Meters(2.0)..=Meters(4.0), NoiseKind::Value);
+        noise.sample(0.0);
+    }
+
+    #[test]
+    fn distributions_stay_in_range() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0);
+        for distribution in [
+            PropDistribution::Uniform,
+            PropDistribution::Normal {
+                mean: 0.5,
+                std_dev: 0.5,
+            },
+            PropDistribution::Triangular { mode: 0.2 },
+            PropDistribution::Exponential { rate: 4.0 },
+            PropDistribution::from_cdf(|x| x * x, 16),
+        ] {
+            let floats = Property::Distribution {
+                range: (Vec2::new(-1.0, 5.0)..Vec2::new(1.0, 10.0)).into(),
+                distribution: distribution.clone(),
+            };
+            let ints: Property<(i8, u8)> = Property::Distribution {
+                range: ((-100, 0)..=(100, 255)).into(),
+                distribution,
+            };
+            for _ in 0..100 {
+                let v = floats.get_value_with(&mut rng);
+                assert!((-1.0..1.0).contains(&v.x) && (5.0..10.0).contains(&v.y));
+                let (x, _) = ints.get_value_with(&mut rng);
+                assert!((-100..=100).contains(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn triangular_distribution_skew() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0);
+        let p: Property<f64> = Property::Distribution {
+            range: (0.0..=3.0).into(),
+            distribution: PropDistribution::Triangular { mode: 0.0 },
+        };
+        let mean = (0..2000).map(|_| p.get_value_with(&mut rng)).sum::<f64>() / 2000.0;
+        // The mean of a triangular distribution is (min + max + mode) / 3
+        assert!((0.9..1.1).contains(&mean), "{} is far from 1.0", mean);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_format() {
+        let cases: [(Property<f32>, &str); 7] = [
+            (5.0.into(), "5.0"),
+            (
+                (0.0..=1.0).into(),
+                r#"{"range":[0.0,1.0],"inclusive":true}"#,
+            ),
+            (vec![1.0, 2.0, 3.0].into(), "[1.0,2.0,3.0]"),
+            (Property::Random, r#""random""#),
+            (
+                vec![(1.0, 1.0), (2.0, 3.0)].into(),
+                r#"{"weighted":[[1.0,1.0],[2.0,3.0]]}"#,
+            ),
+            (
+                PropSequence::new(vec![1.0, 2.0], prop_sequence::SequenceMode::PingPong).into(),
+                r#"{"sequence":[1.0,2.0],"mode":"ping_pong"}"#,
+            ),
+            (
+                Property::Distribution {
+                    range: (0.0..1.0).into(),
+                    distribution: PropDistribution::Triangular { mode: 0.5 },
+                },
+                r#"{"range":[0.0,1.0],"inclusive":false,"distribution":{"triangular":{"mode":0.5}}}"#,
+            ),
+        ];
+        for (p, json) in cases {
+            assert_eq!(serde_json::to_string(&p).unwrap(), json);
+            let back: Property<f32> = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_static_or_choice_lists() {
+        let p: Property<Vec2> = serde_json::from_str("[1.0, 2.0]").unwrap();
+        assert!(matches!(p, Property::Static(v) if v == Vec2::new(1.0, 2.0)));
+        let p: Property<Vec2> = serde_json::from_str("[[1.0, 2.0], [3.0, 4.0]]").unwrap();
+        assert!(matches!(p, Property::RandomChoice(v) if v.len() == 2));
+        let json = r#"{"in_shape":{"circle":{"radius":1.0}}}"#;
+        let p: Property<Vec2> = serde_json::from_str(json).unwrap();
+        assert!(matches!(p, Property::InShape(_)));
+        assert_eq!(serde_json::to_string(&p).unwrap(), json);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_interval_property() {
+        use interval_property::IntervalProperty;
+        let mut p = IntervalProperty::<Property<f32>>::new((0.0..1.0).into(), 2.0);
+        p.tick_value(core::time::Duration::from_secs(3));
+        let json = serde_json::to_string(&p).unwrap();
+        let back: IntervalProperty<Property<f32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.get_curr_value(), p.get_curr_value());
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+
+        let mut p = IntervalProperty::new_with_initial_value(Property::Static(10.0f32), 1.0, 0.0)
+            .with_transition(bevy_math::curve::EaseFunction::Linear);
+        let json = serde_json::to_string(&p).unwrap();
+        let mut back: IntervalProperty<Property<f32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+        let tick = |p: &mut IntervalProperty<Property<f32>>| {
+            p.tick_value(core::time::Duration::from_secs(1));
+            p.tick_value(core::time::Duration::from_secs_f32(0.5))
+                .copied()
+        };
+        assert_eq!(tick(&mut back), Some(5.0));
+        assert_eq!(tick(&mut p), Some(5.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_property_binding() {
+        use bevy_reflect::{
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+            FromReflect, GetTypeRegistration, TypeRegistry,
+        };
+        use dynamic_property::{DynamicProperty, ReflectVariableProperty};
+        use interval_property::IntervalProperty;
+        use property_binding::PropertyBinding;
+        use serde::de::DeserializeSeed;
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<PropertyBinding>();
+        registry.register::<Property<f32>>();
+        registry.register_type_data::<Property<f32>, ReflectVariableProperty>();
+        let mut binding = PropertyBinding::new(
+            IntervalProperty::new(DynamicProperty::new(Property::from(1.0f32..2.0)), 1.0),
+            "Transform",
+            "translation.x",
+        );
+        binding
+            .property
+            .tick_value(core::time::Duration::from_secs(1));
+        let json =
+            serde_json::to_string(&TypedReflectSerializer::new(&binding, &registry)).unwrap();
+        let registration = PropertyBinding::get_type_registration();
+        let back = TypedReflectDeserializer::new(&registration, &registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        let mut back = PropertyBinding::from_reflect(back.as_ref()).unwrap();
+        assert_eq!(back.field, "translation.x");
+        let curr = back.property.get_curr_value().unwrap();
+        assert_eq!(
+            curr.try_downcast_ref::<f32>(),
+            binding
+                .property
+                .get_curr_value()
+                .and_then(|value| value.try_downcast_ref::<f32>())
+        );
+        back.property.force_regenerate();
+        let value = back
+            .property
+            .tick_value(core::time::Duration::ZERO)
+            .unwrap();
+        assert!((1.0..2.0).contains(value.try_downcast_ref::<f32>().unwrap()));
+
+        // Properties whose type isn't registered can't be deserialized.
+        let mut registry = TypeRegistry::new();
+        registry.register::<PropertyBinding>();
+        registry.register::<Property<f32>>();
+        assert!(TypedReflectDeserializer::new(&registration, &registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .is_err());
+    }
+
+    #[cfg(feature = "asset")]
+    #[test]
+    fn ron_presets() {
+        let p: Property<f32> = ron::from_str("(range: (0.0, 1.0), inclusive: true)").unwrap();
+        assert!(matches!(p, Property::RandomRange(r) if r.inclusive && r.end == 1.0));
+        let p: Property<Vec2> = ron::from_str(r#""random""#).unwrap();
+        assert!(matches!(p, Property::Random));
+        let p: Property<Vec2> = ron::from_str("[(1.0, 2.0), (3.0, 4.0)]").unwrap();
+        assert!(matches!(p, Property::RandomChoice(v) if v.len() == 2));
+        let p: interval_property::IntervalProperty<Property<f32>> =
+            ron::from_str("(property: 5.0, interval: 0.5)").unwrap();
+        assert!(p.get_curr_value().is_none());
+    }
+
+    #[cfg(feature = "asset")]
+    #[test]
+    fn preset_loading_and_hot_swap() {
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::{AssetPlugin, AssetServer, AssetTrackingSystems, Assets};
+        use bevy_ecs::schedule::IntoScheduleConfigs;
+        use interval_property::IntervalProperty;
+        use preset::{PresetProperty, PresetSource, PropertyPreset, PropertyPresetPlugin};
+
+        let dir = std::env::temp_dir().join(format!("variable_property_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("steps.prop.ron"), "(sequence: [1.0, 2.0, 3.0])").unwrap();
+        std::fs::write(
+            dir.join("interval.prop.ron"),
+            "(property: 5.0, interval: 0.5)",
+        )
+        .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: dir.to_string_lossy().into_owned(),
+                ..Default::default()
+            },
+            PropertyPresetPlugin::<Property<f32>>::default(),
+            PropertyPresetPlugin::<IntervalProperty<Property<f32>>>::default(),
+        ))
+        .add_systems(
+            bevy_app::PreUpdate,
+            PresetSource::<IntervalProperty<Property<f32>>>::system::<TestSource>
+                .after(AssetTrackingSystems),
+        );
+        let server = app.world().resource::<AssetServer>().clone();
+        let steps = server.load::<PropertyPreset<Property<f32>>>("steps.prop.ron");
+        let interval =
+            server.load::<PropertyPreset<IntervalProperty<Property<f32>>>>("interval.prop.ron");
+        let sequence = app
+            .world_mut()
+            .spawn(PresetProperty::new(steps.clone()))
+            .id();
+        let source = app
+            .world_mut()
+            .spawn((
+                TestSource(IntervalProperty::new(0.0.into(), 1.0)),
+                PresetSource::new(interval.clone()),
+            ))
+            .id();
+        for _ in 0..1000 {
+            app.update();
+            if server.is_loaded(&steps) && server.is_loaded(&interval) {
+                break;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+        app.update();
+
+        // Sequence presets advance through the copy on the component.
+        let mut p = app
+            .world_mut()
+            .get_mut::<PresetProperty<Property<f32>>>(sequence)
+            .unwrap();
+        assert_eq!(p.next_value(), Some(1.0));
+        assert_eq!(p.next_value(), Some(2.0));
+        let source_value = app
+            .world()
+            .get::<TestSource>(source)
+            .unwrap()
+            .0
+            .property()
+            .get_value();
+        assert_eq!(source_value, 5.0);
+
+        // Modified presets are copied in again.
+        app.world_mut()
+            .resource_mut::<Assets<PropertyPreset<Property<f32>>>>()
+            .get_mut(&steps)
+            .unwrap()
+            .preset = Property::Static(7.0);
+        app.world_mut()
+            .resource_mut::<Assets<PropertyPreset<IntervalProperty<Property<f32>>>>>()
+            .get_mut(&interval)
+            .unwrap()
+            .preset = IntervalProperty::new(8.0.into(), 2.0);
+        app.update();
+        app.update();
+        let mut p = app
+            .world_mut()
+            .get_mut::<PresetProperty<Property<f32>>>(sequence)
+            .unwrap();
+        assert_eq!(p.next_value(), Some(7.0));
+        let source_value = app
+            .world()
+            .get::<TestSource>(source)
+            .unwrap()
+            .0
+            .property()
+            .get_value();
+        assert_eq!(source_value, 8.0);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn prop_array_generation() {
+        use crate::prop_array::PropArray;
+        let p: Property<PropArray<f32, 4>> =
+            (PropArray([0.0, 0.5, 0.0, 1.0])..=PropArray([0.5, 1.0, 0.2, 1.0])).into();
+        let v: Vec4 = p.get_value().into();
+        assert!(v.cmpge(Vec4::new(0.0, 0.5, 0.0, 1.0)).all());
+        assert!(v.cmple(Vec4::new(0.5, 1.0, 0.2, 1.0)).all());
+        #[cfg(feature = "color")]
+        {
+            let color: bevy_color::LinearRgba = p.get_value().into();
+            assert_eq!(color.alpha, 1.0);
+        }
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn color_generation() {
+        use bevy_color::{Hsla, Oklcha, Srgba};
+        let p: Property<Hsla> = (Hsla::hsl(350.0, 1.0, 0.5)..=Hsla::hsl(10.0, 1.0, 0.5)).into();
+        for _ in 0..100 {
+            let hue = p.get_value().hue;
+            assert!(
+                hue >= 350.0 || hue <= 10.0,
+                "{} did not wrap around the hue circle",
+                hue
+            );
+        }
+        let p: Property<Oklcha> = Property::Random;
+        assert_eq!(p.get_value().alpha, 1.0);
+        let p: Property<Srgba> = vec![Srgba::RED, Srgba::BLUE].into();
+        let color = p.get_value();
+        assert!(color == Srgba::RED || color == Srgba::BLUE);
+        let p: Property<Srgba> = (Srgba::RED..Srgba::new(f32::INFINITY, 0.0, 0.0, 1.0)).into();
+        assert_eq!(
+            p.validate(),
+            Err(property_error::PropertyError::NonFiniteBound)
+        );
+    }
+
+    #[test]
+    fn rotation_generation() {
+        let p: Property<Dir3> = Property::Random;
+        for _ in 0..100 {
+            assert!(p.get_value().is_normalized());
+        }
+        let end = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let p: Property<Quat> = (Quat::IDENTITY..=end).into();
+        for _ in 0..100 {
+            let q = p.get_value();
+            assert!(q.is_normalized());
+            assert!(q.angle_between(Quat::IDENTITY) <= std::f32::consts::FRAC_PI_2 + 1e-4);
+            assert!(
+                (q.to_axis_angle().0 - Vec3::Z).length() < 1e-3
+                    || q.angle_between(Quat::IDENTITY) < 1e-3
+            );
+        }
+        let p: Property<Mat4> = (Mat4::IDENTITY..Mat4::from_translation(Vec3::X)).into();
+        let translation = p.get_value().to_scale_rotation_translation().2;
+        assert!((0.0..1.0).contains(&translation.x) && translation.y == 0.0);
+    }
+
+    #[test]
+    fn shape_generation() {
+        use bevy_math::primitives::{Annulus, Cuboid, Sphere, Triangle3d};
+        use prop_shape::PropShape;
+        let p: Property<Vec2> = Property::in_shape(Annulus::new(1.0, 2.0));
+        for _ in 0..100 {
+            let length = p.get_value().length();
+            assert!(
+                (1.0..=2.0).contains(&length),
+                "{} is outside the annulus",
+                length
+            );
+        }
+        let p: Property<Vec3> = Property::on_shape(Sphere::new(3.0));
+        assert!((p.get_value().length() - 3.0).abs() < 1e-4);
+        let p: Property<Vec3> = Property::in_shape(Cuboid::new(2.0, 2.0, 2.0));
+        assert!(p.get_value().abs().cmple(Vec3::ONE).all());
+
+        // A closed cube mesh from -1 to 1, two triangles per face
+        let mut triangles = Vec::new();
+        for axis in 0..3 {
+            for side in [-1.0, 1.0] {
+                let corner = |a: f32, b: f32| {
+                    let mut v = Vec3::splat(side);
+                    v[(axis + 1) % 3] = a;
+                    v[(axis + 2) % 3] = b;
+                    v
+                };
+                let (a, b, c, d) = (
+                    corner(-1.0, -1.0),
+                    corner(1.0, -1.0),
+                    corner(1.0, 1.0),
+                    corner(-1.0, 1.0),
+                );
+                triangles.push(Triangle3d::new(a, b, c));
+                triangles.push(Triangle3d::new(a, c, d));
+            }
+        }
+        let on_mesh: Property<Vec3> = Property::on_shape(PropShape::mesh(triangles.clone()));
+        let in_mesh: Property<Vec3> = Property::in_shape(triangles);
+        for _ in 0..100 {
+            let v = on_mesh.get_value();
+            assert!(
+                (v.abs().max_element() - 1.0).abs() < 1e-5,
+                "{v} isn't on the cube"
+            );
+            assert!(in_mesh.get_value().abs().cmple(Vec3::ONE).all());
+        }
+        let mut rng = rand::thread_rng();
+        let inside = (0..100)
+            .filter(|_| in_mesh.get_value().abs().max_element() < 0.99)
+            .count();
+        assert!(inside > 90, "only {inside} points were inside the mesh");
+
+        // Shapes the type can't sample from are errors instead of panics
+        use property_error::PropertyError;
+        assert!(PropShape::from(Sphere::new(1.0))
+            .sample_2d(&mut rng, false)
+            .is_err());
+        let p: Property<Vec2> = Property::in_shape(Sphere::new(1.0));
+        assert_eq!(p.try_get_value(), Err(PropertyError::UnsupportedShape));
+        let p: Property<f32> = Property::on_shape(Annulus::new(1.0, 2.0));
+        assert_eq!(p.validate(), Err(PropertyError::UnsupportedShape));
+        let p: Property<Vec3> = Property::in_shape(PropShape::mesh([]));
+        assert_eq!(p.try_get_value(), Err(PropertyError::EmptyShape));
+    }
+
+    #[test]
+    #[should_panic(expected = "points of this type can't be sampled from the shape")]
+    fn bad_shape() {
+        let p: Property<Vec2> = Property::in_shape(bevy_math::primitives::Sphere::new(1.0));
+        p.get_value();
+    }
+
+    #[test]
+    fn combinators() {
+        use crate::combinators::{PropAdd, PropClamp, PropMap, PropMul};
+        use interval_property::IntervalProperty;
+
+        let speed: PropClamp<PropMap<_, f32>> = Property::Static(10.0f32)
+            .mul(Property::<f32>::from(0.5..1.5))
+            .map(|v| v * 2.0)
+            .clamp(12.0, 25.0);
+        for _ in 0..100 {
+            assert!((12.0..=25.0).contains(&speed.get_value()));
+        }
+
+        let position: PropAdd<Property<Vec2>, Property<Vec2>> =
+            Property::Static(Vec2::splat(100.0))
+                .add(Property::from(Vec2::splat(-1.0)..Vec2::splat(1.0)));
+        let mut interval = IntervalProperty::new(position, 1.0);
+        let v = *interval
+            .tick_value(core::time::Duration::from_secs(1))
+            .unwrap();
+        assert!(v.cmpgt(Vec2::splat(98.9)).all() && v.cmplt(Vec2::splat(101.0)).all());
+
+        let p = Property::from(vec![1u8, 2]).then(|n| Property::Static(n as u32 * 10));
+        assert!([10, 20].contains(&p.get_value()));
+        let (a, b) = Property::Static(1).zip(Property::Static(2.0)).get_value();
+        assert_eq!((a, b), (1, 2.0));
+
+        // Map, add and mul interpolate their outputs, for transitions
+        assert_eq!(
+            PropMap::<Property<f32>, f32>::interpolate(&2.0, &4.0, 0.5),
+            Some(3.0)
+        );
+        assert_eq!(
+            PropAdd::<Property<Vec2>, Property<Vec2>>::interpolate(&Vec2::ZERO, &Vec2::ONE, 0.5),
+            Some(Vec2::splat(0.5))
+        );
+        assert_eq!(
+            PropMul::<Property<u8>, Property<u8>>::interpolate(&10, &20, 0.26),
+            Some(13)
+        );
+    }
+
+    #[test]
+    fn derives() {
+        use property_error::PropertyError;
+
+        #[derive(PropRand, Reflect, Clone, Copy, Debug)]
+        struct Stats {
+            health: u32,
+            speed: f32,
+        }
+
+        #[derive(VariableProperty)]
+        #[variable_property(output = Stats)]
+        struct StatsProperty {
+            health: Property<u32>,
+            speed: Property<f32>,
+        }
+
+        let stats = Property::RandomRange(PropRange::new(
+            Stats {
+                health: 10,
+                speed: 1.0,
+            },
+            Stats {
+                health: 20,
+                speed: 2.0,
+            },
+            false,
+        ));
+        for _ in 0..100 {
+            let v = stats.get_value();
+            assert!((10..20).contains(&v.health) && (1.0..2.0).contains(&v.speed));
+        }
+
+        let stats = StatsProperty {
+            health: Property::from(vec![1, 2]),
+            speed: Property::Static(5.0),
+        }
+        .get_value();
+        assert!([1, 2].contains(&stats.health));
+        assert_eq!(stats.speed, 5.0);
+
+        // Both derives interpolate per field, for transitions
+        let to = Stats {
+            health: 20,
+            speed: 15.0,
+        };
+        let half = StatsProperty::interpolate(&stats, &to, 0.5).unwrap();
+        assert_eq!(half.speed, 10.0);
+        let half = Stats::interpolate(&stats, &to, 0.5).unwrap();
+        assert_eq!(half.speed, 10.0);
+
+        // The derive validates and fixes per field
+        let mut p = Property::RandomRange(PropRange::new(
+            Stats {
+                health: 10,
+                speed: 2.0,
+            },
+            Stats {
+                health: 20,
+                speed: 1.0,
+            },
+            false,
+        ));
+        assert_eq!(p.validate(), Err(PropertyError::ReversedBounds));
+        assert_eq!(p.fix_reversed_bounds(), Ok(()));
+        let v = p.get_value();
+        assert!((10..20).contains(&v.health) && (1.0..2.0).contains(&v.speed));
+
+        // A renamed crate is given with the crate argument
+        use crate as renamed;
+
+        #[derive(PropRand, Reflect, Clone, Copy, Debug, PartialEq)]
+        #[prop_rand(crate = renamed)]
+        struct Offset(f32);
+
+        #[derive(VariableProperty)]
+        #[variable_property(output = Offset, crate = renamed)]
+        struct OffsetProperty(Property<f32>);
+
+        let p = OffsetProperty(Property::Static(2.0));
+        assert_eq!(p.get_value(), Offset(2.0));
+        let half = OffsetProperty::interpolate(&Offset(0.0), &Offset(1.0), 0.5);
+        assert_eq!(half, Some(Offset(0.5)));
+    }
+
+    #[derive(Component, Reflect)]
+    struct TestSource(interval_property::IntervalProperty<Property<f32>>);
+
+    impl AsMut<interval_property::IntervalProperty<Property<f32>>> for TestSource {
+        fn as_mut(&mut self) -> &mut interval_property::IntervalProperty<Property<f32>> {
+            &mut self.0
+        }
+    }
+
+    #[derive(Component, Reflect, Default)]
+    struct TestTarget(f32);
+
+    impl IntervalPropertyComponent for TestSource {
+        type Property = Property<f32>;
+        type TargetComponent = TestTarget;
+
+        fn update(new_value: &f32, target: &mut TestTarget) {
+            target.0 = *new_value;
+        }
+    }
+
+    #[test]
+    fn default_distributions() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // Only implements the required methods
+        #[derive(Clone, Debug, PartialEq, bevy_reflect::TypePath)]
+        struct Percent(u8);
+        impl PropRand for Percent {
+            fn gen<R: rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self(u8::gen_range(rng, (0..=100).into()))
+            }
+            fn gen_range<R: rand::RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                let range = PropRange::new(range.start.0, range.end.0, range.inclusive);
+                Self(u8::gen_range(rng, range))
+            }
+        }
+
+        // Maps unit values, so it supports every distribution
+        #[derive(Clone, Debug, PartialEq, bevy_reflect::TypePath)]
+        struct Meters(f32);
+        impl PropRand for Meters {
+            fn gen<R: rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+                Self(f32::gen(rng))
+            }
+            fn gen_range<R: rand::RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                let range = PropRange::new(range.start.0, range.end.0, range.inclusive);
+                Self(f32::gen_range(rng, range))
+            }
+            fn from_units<F: FnMut() -> f64>(
+                range: PropRange<Self>,
+                units: &mut F,
+            ) -> Option<Self> {
+                let range = PropRange::new(range.start.0, range.end.0, range.inclusive);
+                f32::from_units(range, units).map(Self)
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let percent = Property::Distribution {
+            range: (Percent(20)..Percent(40)).into(),
+            distribution: PropDistribution::Uniform,
+        };
+        let meters = Property::Distribution {
+            range: (Meters(2.0)..Meters(4.0)).into(),
+            distribution: PropDistribution::Triangular { mode: 0.5 },
+        };
+        for _ in 0..100 {
+            assert!((20..40).contains(&percent.get_value_with(&mut rng).0));
+            assert!((2.0..4.0).contains(&meters.get_value_with(&mut rng).0));
+        }
+        assert_eq!(
+            Percent::from_units((Percent(0)..Percent(1)).into(), &mut || 0.5),
+            None
+        );
+        // A unit value of 1.0 stays below an exclusive end.
+        assert_eq!(u8::from_units((0..10).into(), &mut || 1.0), Some(9));
+    }
+
+    #[test]
+    fn seeded_interval_property_component() {
+        use bevy_ecs::{prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+
+        let run = |seeds: &[u64]| {
+            let mut world = World::new();
+            let mut time = Time::<()>::default();
+            time.advance_by(Duration::from_secs(1));
+            world.insert_resource(time);
+            world.insert_resource(rng::PropertyRng::new(7));
+            let entities = seeds
+                .iter()
+                .map(|seed| {
+                    world
+                        .spawn((
+                            TestSource(interval_property::IntervalProperty::new(
+                                (0.0..1.0).into(),
+                                1.0,
+                            )),
+                            TestTarget::default(),
+                            rng::PropertyRngSeed::new(*seed),
+                        ))
+                        .id()
+                })
+                .collect::<Vec<_>>();
+            world.run_system_once(TestSource::system).unwrap();
+            entities
+                .into_iter()
+                .map(|e| world.get::<TestTarget>(e).unwrap().0)
+                .collect::<Vec<_>>()
+        };
+
+        let forward = run(&[1, 2, 3]);
+        let mut backward = run(&[3, 2, 1]);
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn regenerated_events() {
+        use bevy_ecs::{message::Messages, prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use interval_property::PropertyRegenerated;
+
+        #[derive(Resource, Default)]
+        struct Observed(Vec<(Entity, f32)>);
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Observed>();
+        world.init_resource::<Messages<PropertyRegenerated<TestSource>>>();
+        world.add_observer(
+            |event: On<PropertyRegenerated<TestSource>>, mut observed: ResMut<Observed>| {
+                observed.0.push((event.entity, event.value));
+            },
+        );
+        let entity = world
+            .spawn((
+                TestSource(interval_property::IntervalProperty::new(
+                    (0.0..1.0).into(),
+                    1.0,
+                )),
+                TestTarget::default(),
+            ))
+            .id();
+        world
+            .run_system_once(TestSource::system_with_events)
+            .unwrap();
+
+        let value = world.get::<TestTarget>(entity).unwrap().0;
+        assert_eq!(world.resource::<Observed>().0, [(entity, value)]);
+        let messages = world.resource::<Messages<PropertyRegenerated<TestSource>>>();
+        let written = messages.iter_current_update_messages().collect::<Vec<_>>();
+        assert!(matches!(written[..], [m] if m.entity == entity && m.value == value));
+    }
+
+    #[test]
+    fn system_without_clone() {
+        use bevy_ecs::{prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use interval_property::IntervalProperty;
+
+        // Values that can't be cloned, so they can't be sent as events.
+        #[derive(bevy_reflect::TypePath)]
+        struct Token(f32);
+
+        #[derive(Reflect)]
+        struct TokenProperty;
+
+        impl VariableProperty for TokenProperty {
+            type Output = Token;
+
+            fn get_value_with(&self, _rng: &mut dyn rand::RngCore) -> Token {
+                Token(3.0)
+            }
+        }
+
+        #[derive(Component)]
+        struct TokenSource(IntervalProperty<TokenProperty>);
+
+        impl AsMut<IntervalProperty<TokenProperty>> for TokenSource {
+            fn as_mut(&mut self) -> &mut IntervalProperty<TokenProperty> {
+                &mut self.0
+            }
+        }
+
+        impl IntervalPropertyComponent for TokenSource {
+            type Property = TokenProperty;
+            type TargetComponent = TestTarget;
+
+            fn update(new_value: &Token, target: &mut TestTarget) {
+                target.0 = new_value.0;
+            }
+        }
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        let entity = world
+            .spawn((
+                TokenSource(IntervalProperty::new(TokenProperty, 1.0)),
+                TestTarget::default(),
+            ))
+            .id();
+        world.run_system_once(TokenSource::system).unwrap();
+        assert_eq!(world.get::<TestTarget>(entity).unwrap().0, 3.0);
+    }
+
+    #[test]
+    fn pause_modes_and_commands() {
+        use bevy_ecs::{prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use interval_property::{IntervalPropertyCommandsExt, PauseIntervalProperty, PauseMode};
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let entity = world
+            .spawn((
+                TestSource(interval_property::IntervalProperty::new(
+                    PropSequence::from(vec![1.0, 2.0, 3.0, 4.0]).into(),
+                    1.0,
+                )),
+                TestTarget::default(),
+            ))
+            .id();
+        let run = |world: &mut World, secs: f32| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            world.run_system_once(TestSource::system).unwrap();
+            world.get::<TestTarget>(entity).unwrap().0
+        };
+        let pause = |world: &mut World, mode: Option<PauseMode>| {
+            let mut entity = world.entity_mut(entity);
+            match mode {
+                Some(mode) => entity.insert(PauseIntervalProperty::<TestSource>::new(mode)),
+                None => entity.remove::<PauseIntervalProperty<TestSource>>(),
+            };
+        };
+
+        assert_eq!(run(&mut world, 1.0), 1.0);
+        pause(&mut world, Some(PauseMode::Skip));
+        assert_eq!(run(&mut world, 1.0), 1.0);
+        pause(&mut world, Some(PauseMode::Queue));
+        assert_eq!(run(&mut world, 1.0), 1.0);
+        pause(&mut world, None);
+        assert_eq!(run(&mut world, 0.0), 3.0);
+
+        pause(&mut world, Some(PauseMode::Freeze));
+        assert_eq!(run(&mut world, 5.0), 3.0);
+        pause(&mut world, None);
+        assert_eq!(run(&mut world, 0.5), 3.0);
+        assert_eq!(run(&mut world, 0.5), 4.0);
+
+        world
+            .commands()
+            .entity(entity)
+            .regenerate_interval_property::<TestSource>();
+        world.flush();
+        assert_eq!(run(&mut world, 0.0), 1.0);
+        assert_eq!(run(&mut world, 0.5), 1.0);
+        world
+            .commands()
+            .entity(entity)
+            .reset_interval_property::<TestSource>();
+        world.flush();
+        assert_eq!(run(&mut world, 0.5), 1.0);
+        assert_eq!(run(&mut world, 0.5), 2.0);
+    }
+
+    #[test]
+    fn custom_clock_and_time_scale() {
+        use bevy_ecs::{prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use interval_property::PropertyTimeScale;
+
+        #[derive(Default)]
+        struct UiClock;
+
+        let mut world = World::new();
+        let mut time = Time::<UiClock>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        let mut spawn = |scale: Option<f32>| {
+            let mut entity = world.spawn((
+                TestSource(interval_property::IntervalProperty::new(
+                    (1.0..2.0).into(),
+                    1.0,
+                )),
+                TestTarget::default(),
+            ));
+            if let Some(scale) = scale {
+                entity.insert(PropertyTimeScale(scale));
+            }
+            entity.id()
+        };
+        let (normal, fast) = (spawn(None), spawn(Some(2.0)));
+        world
+            .run_system_once(TestSource::system_with_clock::<UiClock>)
+            .unwrap();
+        assert_eq!(world.get::<TestTarget>(normal).unwrap().0, 0.0);
+        assert!(world.get::<TestTarget>(fast).unwrap().0 >= 1.0);
+    }
+
+    #[test]
+    fn property_targets() {
+        use bevy_ecs::{prelude::*, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use property_target::{PropertyTargetMode, PropertyTargetOf, TargetComponents};
+
+        #[derive(Component)]
+        struct TargetedSource(
+            interval_property::IntervalProperty<Property<f32>>,
+            PropertyTargetMode,
+        );
+
+        impl AsMut<interval_property::IntervalProperty<Property<f32>>> for TargetedSource {
+            fn as_mut(&mut self) -> &mut interval_property::IntervalProperty<Property<f32>> {
+                &mut self.0
+            }
+        }
+
+        #[derive(Component, Default)]
+        struct OtherTarget(f32);
+
+        impl IntervalPropertyComponent for TargetedSource {
+            type Property = Property<f32>;
+            type TargetComponent = TargetComponents<(TestTarget, OtherTarget)>;
+
+            fn update(new_value: &f32, (target, other): &mut (Mut<TestTarget>, Mut<OtherTarget>)) {
+                target.0 = *new_value;
+                other.0 = -*new_value;
+            }
+
+            fn target(&self) -> PropertyTargetMode {
+                self.1
+            }
+        }
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        let target = || (TestTarget::default(), OtherTarget::default());
+        let source = |mode| {
+            TargetedSource(
+                interval_property::IntervalProperty::new((1.0..2.0).into(), 1.0),
+                mode,
+            )
+        };
+        let this = world
+            .spawn((source(PropertyTargetMode::This), target()))
+            .id();
+        let parent = world.spawn(target()).id();
+        let child = world
+            .spawn((source(PropertyTargetMode::Parent), ChildOf(parent)))
+            .id();
+        let children = world.spawn(source(PropertyTargetMode::Children)).id();
+        let first = world.spawn((target(), ChildOf(children))).id();
+        let second = world.spawn((target(), ChildOf(children))).id();
+        let explicit = world.spawn(target()).id();
+        world.spawn(source(PropertyTargetMode::Entity(explicit)));
+        let related = world.spawn(source(PropertyTargetMode::Related)).id();
+        let related_target = world.spawn((target(), PropertyTargetOf(related))).id();
+        let missing = world.spawn(TestTarget::default()).id();
+        world.entity_mut(missing).insert(ChildOf(children));
+
+        world.run_system_once(TargetedSource::system).unwrap();
+        for entity in [this, parent, first, second, explicit, related_target] {
+            let value = world.get::<TestTarget>(entity).unwrap().0;
+            assert!((1.0..2.0).contains(&value));
+            assert_eq!(world.get::<OtherTarget>(entity).unwrap().0, -value);
+        }
+        assert!(world.get::<TestTarget>(child).is_none());
+        assert_eq!(world.get::<TestTarget>(missing).unwrap().0, 0.0);
+    }
+
+    #[test]
+    fn property_binding() {
+        use bevy_ecs::{prelude::*, reflect::ReflectComponent, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use dynamic_property::DynamicProperty;
+        use property_binding::{apply_to_field, PropertyBinding, PropertyBindingError};
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Bound {
+            position: Vec2,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Bound>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        let entity = world
+            .spawn((
+                Bound::default(),
+                PropertyBinding::new(
+                    interval_property::IntervalProperty::new(
+                        DynamicProperty::new(Property::from(1.0f32..2.0)),
+                        1.0,
+                    ),
+                    "Bound",
+                    "position.y",
+                ),
+            ))
+            .id();
+        world.run_system_once(PropertyBinding::system).unwrap();
+        let position = world.get::<Bound>(entity).unwrap().position;
+        assert_eq!(position.x, 0.0);
+        assert!((1.0..2.0).contains(&position.y));
+        assert_eq!(world.get::<PropertyBinding>(entity).unwrap().error(), None);
+
+        // Errors are kept on the binding, and cleared once a value applies again.
+        let mut binding = world.get_mut::<PropertyBinding>(entity).unwrap();
+        binding.field = "position.w".to_string();
+        binding.property.force_regenerate();
+        world.run_system_once(PropertyBinding::system).unwrap();
+        assert!(matches!(
+            world.get::<PropertyBinding>(entity).unwrap().error(),
+            Some(PropertyBindingError::InvalidPath { .. })
+        ));
+        let mut binding = world.get_mut::<PropertyBinding>(entity).unwrap();
+        binding.field = "position.x".to_string();
+        binding.property.force_regenerate();
+        world.run_system_once(PropertyBinding::system).unwrap();
+        assert_eq!(world.get::<PropertyBinding>(entity).unwrap().error(), None);
+        assert!((1.0..2.0).contains(&world.get::<Bound>(entity).unwrap().position.x));
+
+        assert!(matches!(
+            apply_to_field(&mut world, entity, "Unknown", "position.x", &1.0f32),
+            Err(PropertyBindingError::UnknownType(_))
+        ));
+        assert!(matches!(
+            apply_to_field(&mut world, entity, "Bound", "position.w", &1.0f32),
+            Err(PropertyBindingError::InvalidPath { .. })
+        ));
+        assert!(matches!(
+            apply_to_field(&mut world, entity, "Bound", "position.x", &1u8),
+            Err(PropertyBindingError::Apply { .. })
+        ));
+    }
+
+    #[test]
+    fn property_binding_warns_once() {
+        use bevy_ecs::{prelude::*, reflect::ReflectComponent, system::RunSystemOnce};
+        use bevy_time::Time;
+        use core::time::Duration;
+        use dynamic_property::DynamicProperty;
+        use property_binding::{apply_to_field, PropertyBinding, PropertyBindingError};
+        use std::sync::Mutex;
+
+        static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        struct TestLogger;
+        impl log::Log for TestLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Warn
+            }
+            fn log(&self, record: &log::Record) {
+                WARNINGS.lock().unwrap().push(record.args().to_string());
+            }
+            fn flush(&self) {}
+        }
+        log::set_logger(&TestLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Warned {
+            position: Vec2,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Warned>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+
+        // Values of the dynamic property apply like any reflected value.
+        let entity = world.spawn(Warned::default()).id();
+        let floats = DynamicProperty::new(Property::Static(3.0f32));
+        apply_to_field(
+            &mut world,
+            entity,
+            "Warned",
+            "position.x",
+            &*floats.get_value(),
+        )
+        .unwrap();
+        assert_eq!(world.get::<Warned>(entity).unwrap().position.x, 3.0);
+        let bytes = DynamicProperty::new(Property::Static(3u8));
+        assert!(matches!(
+            apply_to_field(
+                &mut world,
+                entity,
+                "Warned",
+                "position.x",
+                &*bytes.get_value()
+            ),
+            Err(PropertyBindingError::Apply { .. })
+        ));
+
+        // A binding failing on every value only warns the first time.
+        world.entity_mut(entity).insert(PropertyBinding::new(
+            interval_property::IntervalProperty::new(bytes, 1.0),
+            "Warned",
+            "position.x",
+        ));
+        for _ in 0..3 {
+            world.run_system_once(PropertyBinding::system).unwrap();
+        }
+        assert!(matches!(
+            world.get::<PropertyBinding>(entity).unwrap().error(),
+            Some(PropertyBindingError::Apply { .. })
+        ));
+        let warnings = WARNINGS.lock().unwrap();
+        assert_eq!(
+            warnings
+                .iter()
+                .filter(|warning| warning.contains("applied to `position.x`"))
+                .count(),
+            1,
+            "{warnings:?}"
+        );
+    }
+
+    #[test]
+    fn dynamic_properties() {
+        use bevy_reflect::{PartialReflect, TypeRegistry};
+        use dynamic_property::{DynamicProperty, ReflectVariableProperty};
+
+        let mut properties = [
+            DynamicProperty::new(Property::from(1.0f32..2.0)),
+            DynamicProperty::new(Property::Static(Vec2::ONE)),
+            DynamicProperty::new(Property::Sequence(vec![1u8, 2].into())),
+        ];
+        let values = properties
+            .iter_mut()
+            .map(|property| property.next_value())
+            .collect::<Vec<_>>();
+        assert!((1.0..2.0).contains(values[0].try_downcast_ref::<f32>().unwrap()));
+        assert_eq!(values[1].try_downcast_ref::<Vec2>(), Some(&Vec2::ONE));
+        assert_eq!(values[2].try_downcast_ref::<u8>(), Some(&1));
+        assert_eq!(
+            properties[2].next_value().try_downcast_ref::<u8>(),
+            Some(&2)
+        );
+        assert_eq!(
+            properties[1].property().reflect_type_path(),
+            Property::<Vec2>::type_path()
+        );
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Property<f32>>();
+        registry.register_type_data::<Property<f32>, ReflectVariableProperty>();
+        let reflect_property = registry
+            .get_with_type_path(Property::<f32>::type_path())
+            .and_then(|registration| registration.data::<ReflectVariableProperty>())
+            .unwrap();
+        let serialized = Property::<f32>::from(3.0..4.0).to_dynamic();
+        let value = reflect_property.get_value(serialized.as_ref()).unwrap();
+        assert!((3.0..4.0).contains(value.try_downcast_ref::<f32>().unwrap()));
+        assert!(reflect_property.get_value(&5u8).is_none());
+        let property = DynamicProperty::from_reflect(serialized.as_ref(), &registry).unwrap();
+        assert!((3.0..4.0).contains(property.get_value().try_downcast_ref::<f32>().unwrap()));
+    }
+
+    #[test]
+    fn dynamic_property_transitions() {
+        use dynamic_property::{DynamicProperty, DynamicValue};
+
+        // Driven by an IntervalProperty, values ease through the wrapped type's interpolation.
+        let mut p = interval_property::IntervalProperty::new_with_initial_value(
+            DynamicProperty::new(Property::Static(10.0f32)),
+            1.0,
+            DynamicValue::new(0.0f32),
+        )
+        .with_transition(bevy_math::curve::EaseFunction::Linear);
+        p.tick_value(core::time::Duration::from_secs(1));
+        let value = p.tick_value(core::time::Duration::from_secs_f32(0.5));
+        assert_eq!(
+            value.and_then(|value| value.try_downcast_ref::<f32>()),
+            Some(&5.0)
+        );
+    }
+
+    #[test]
+    fn plugin_registration() {
+        use crate::plugin::{IntervalPropertyAppExt, VariablePropertyPlugin};
+        use bevy_app::{App, FixedUpdate, PostUpdate, Update};
+        use bevy_time::Time;
+        use core::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(VariablePropertyPlugin::new(PostUpdate))
+            .register_interval_property::<TestSource>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        app.insert_resource(time);
+        let entity = app
+            .world_mut()
+            .spawn((
+                TestSource(interval_property::IntervalProperty::new(
+                    (1.0..2.0).into(),
+                    1.0,
+                )),
+                TestTarget::default(),
+            ))
+            .id();
+        app.update();
+        assert!(app.world().get::<TestTarget>(entity).unwrap().0 >= 1.0);
+
+        let registry = app.world().resource::<bevy_ecs::reflect::AppTypeRegistry>();
+        let registry = registry.read();
+        assert!(registry
+            .get(std::any::TypeId::of::<
+                interval_property::PauseIntervalProperty<TestSource>,
+            >())
+            .is_some());
+        assert!(app
+            .get_schedule(PostUpdate)
+            .is_some_and(|schedule| schedule.systems_len() == 1));
+        assert!(app.get_schedule(FixedUpdate).is_none());
+
+        // Registrations made before the plugin wait for its schedule
+        let mut app = App::new();
+        app.register_interval_property::<TestSource>()
+            .register_property_binding();
+        assert!(app
+            .get_schedule(Update)
+            .is_none_or(|schedule| schedule.systems_len() == 0));
+        app.add_plugins(VariablePropertyPlugin::new(FixedUpdate));
+        assert!(app
+            .get_schedule(FixedUpdate)
+            .is_some_and(|schedule| schedule.systems_len() == 2));
+        assert!(app
+            .get_schedule(Update)
+            .is_none_or(|schedule| schedule.systems_len() == 0));
+    }
 }
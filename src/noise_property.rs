@@ -0,0 +1,280 @@
+use bevy_math::Vec3;
+use bevy_reflect::{Reflect, TypePath};
+use core::time::Duration;
+use rand::RngCore;
+
+use crate::prop_rand::PropRand;
+use crate::prop_range::PropRange;
+use crate::variable_property::VariableProperty;
+
+/// The kind of coherent noise sampled by a [NoiseProperty].
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NoiseKind {
+    /// Smoothly interpolated random values on a grid, cheap but blocky
+    Value,
+
+    /// Gradient noise on a grid
+    #[default]
+    Perlin,
+
+    /// Gradient noise on a simplex grid, with fewer directional artifacts than Perlin
+    Simplex,
+}
+
+/// Generates values within a range from coherent noise, so that values close together in time or
+/// space are close together, instead of jumping around like [crate::Property::RandomRange].
+///
+/// Noise is sampled over time with [NoiseProperty::sample] or [NoiseProperty::tick], or over a
+/// position with [NoiseProperty::sample_at]. Each component of the value is given its own noise.
+///
+/// [VariableProperty::next_value] advances by [NoiseProperty::step], so inside an
+/// [IntervalProperty](crate::interval_property::IntervalProperty) each interval moves along the
+/// noise.
+#[derive(Clone, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoiseProperty<T> {
+    /// The range the noise is mapped into
+    pub range: PropRange<T>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub noise: NoiseKind,
+    /// Noise features per second, or per unit for [NoiseProperty::sample_at]
+    #[cfg_attr(feature = "serde", serde(default = "default_one"))]
+    pub frequency: f32,
+    /// Layers of noise added together, each at twice the frequency and half the strength of the
+    /// previous one, adding finer detail
+    #[cfg_attr(feature = "serde", serde(default = "default_octaves"))]
+    pub octaves: u32,
+    /// Scales the noise around the middle of the range, 1.0 spans the whole range
+    #[cfg_attr(feature = "serde", serde(default = "default_one"))]
+    pub amplitude: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seed: u32,
+    /// Seconds advanced by [NoiseProperty::tick], sampled by [VariableProperty::get_value]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub elapsed: f32,
+    /// Seconds advanced by each [VariableProperty::next_value]
+    #[cfg_attr(feature = "serde", serde(default = "default_step"))]
+    pub step: f32,
+}
+
+#[cfg(feature = "serde")]
+fn default_one() -> f32 {
+    1.0
+}
+
+#[cfg(feature = "serde")]
+fn default_octaves() -> u32 {
+    1
+}
+
+#[cfg(feature = "serde")]
+fn default_step() -> f32 {
+    DEFAULT_STEP
+}
+
+const DEFAULT_STEP: f32 = 0.1;
+
+impl<T> NoiseProperty<T> {
+    /// A single octave of noise spanning the whole range, with a frequency of 1 and a step of 0.1
+    /// seconds.
+    pub fn new(range: impl Into<PropRange<T>>, noise: NoiseKind) -> Self {
+        Self {
+            range: range.into(),
+            noise,
+            frequency: 1.0,
+            octaves: 1,
+            amplitude: 1.0,
+            seed: 0,
+            elapsed: 0.0,
+            step: DEFAULT_STEP,
+        }
+    }
+}
+
+impl<T: PropRand + Clone> NoiseProperty<T> {
+    /// Samples the noise at the given time in seconds.
+    pub fn sample(&self, time: f32) -> T {
+        self.sample_at(Vec3::new(time, 0.0, 0.0))
+    }
+
+    /// Samples the noise at the given position, ie: for wind varying across a field.
+    pub fn sample_at(&self, position: Vec3) -> T {
+        let mut component = 0;
+        T::from_units(self.range.clone(), &mut || {
+            component += 1;
+            self.unit(position, component)
+        })
+        .unwrap_or_else(|| panic!("cannot map {} from unit values", std::any::type_name::<T>()))
+    }
+
+    /// Advances [NoiseProperty::elapsed] by the given delta and samples the noise there.
+    pub fn tick(&mut self, delta: Duration) -> T {
+        self.elapsed += delta.as_secs_f32();
+        self.sample(self.elapsed)
+    }
+
+    /// Layers the octaves for one component, mapped from -1.0..=1.0 into 0.0..=1.0.
+    fn unit(&self, position: Vec3, component: u32) -> f64 {
+        let seed = hash(component as i32, 0, 0, self.seed);
+        // Offsets each component away from the lattice points, where gradient noise is always 0.
+        let offset = Vec3::new(
+            unit_hash(0, 1, 0, seed),
+            unit_hash(0, 2, 0, seed),
+            unit_hash(0, 3, 0, seed),
+        ) * 256.0;
+        let mut position = position * self.frequency + offset;
+        let (mut total, mut strength, mut max) = (0.0, 1.0, 0.0);
+        for octave in 0..self.octaves.max(1) {
+            let seed = seed.wrapping_add(octave);
+            total += strength
+                * match self.noise {
+                    NoiseKind::Value => value_noise(position, seed),
+                    NoiseKind::Perlin => perlin_noise(position, seed),
+                    NoiseKind::Simplex => simplex_noise(position, seed),
+                };
+            max += strength;
+            strength *= 0.5;
+            position *= 2.0;
+        }
+        (0.5 + 0.5 * self.amplitude * total / max).clamp(0.0, 1.0) as f64
+    }
+}
+
+impl<T: PropRand + Clone + TypePath> VariableProperty for NoiseProperty<T> {
+    type Output = T;
+
+    /// Samples the noise at [NoiseProperty::elapsed], the rng isn't used.
+    fn get_value_with(&self, _rng: &mut dyn RngCore) -> T {
+        self.sample(self.elapsed)
+    }
+
+    /// Advances [NoiseProperty::elapsed] by [NoiseProperty::step] and samples the noise there.
+    fn next_value_with(&mut self, _rng: &mut dyn RngCore) -> T {
+        self.elapsed += self.step;
+        self.sample(self.elapsed)
+    }
+
+    fn interpolate(from: &T, to: &T, t: f32) -> Option<T> {
+        T::interpolate(from, to, t)
+    }
+}
+
+fn hash(x: i32, y: i32, z: i32, seed: u32) -> u32 {
+    let mut h = seed
+        ^ (x as u32).wrapping_mul(0x8da6_b343)
+        ^ (y as u32).wrapping_mul(0xd816_3841)
+        ^ (z as u32).wrapping_mul(0xcb1a_b31f);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^ (h >> 16)
+}
+
+fn unit_hash(x: i32, y: i32, z: i32, seed: u32) -> f32 {
+    hash(x, y, z, seed) as f32 / u32::MAX as f32
+}
+
+/// The twelve directions to the edges of a cube, picked by the hash.
+fn gradient(hash: u32, d: Vec3) -> f32 {
+    const GRADIENTS: [Vec3; 12] = [
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(-1.0, 1.0, 0.0),
+        Vec3::new(1.0, -1.0, 0.0),
+        Vec3::new(-1.0, -1.0, 0.0),
+        Vec3::new(1.0, 0.0, 1.0),
+        Vec3::new(-1.0, 0.0, 1.0),
+        Vec3::new(1.0, 0.0, -1.0),
+        Vec3::new(-1.0, 0.0, -1.0),
+        Vec3::new(0.0, 1.0, 1.0),
+        Vec3::new(0.0, -1.0, 1.0),
+        Vec3::new(0.0, 1.0, -1.0),
+        Vec3::new(0.0, -1.0, -1.0),
+    ];
+    GRADIENTS[(hash % 12) as usize].dot(d)
+}
+
+/// Trilinearly interpolates `corner` over the cell containing `p`, with a quintic fade.
+fn lattice_noise(p: Vec3, corner: impl Fn(i32, i32, i32, Vec3) -> f32) -> f32 {
+    let cell = p.floor();
+    let f = p - cell;
+    let u = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+    let (x, y, z) = (cell.x as i32, cell.y as i32, cell.z as i32);
+    let c = |dx: i32, dy: i32, dz: i32| {
+        corner(
+            x + dx,
+            y + dy,
+            z + dz,
+            f - Vec3::new(dx as f32, dy as f32, dz as f32),
+        )
+    };
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    lerp(
+        lerp(
+            lerp(c(0, 0, 0), c(1, 0, 0), u.x),
+            lerp(c(0, 1, 0), c(1, 1, 0), u.x),
+            u.y,
+        ),
+        lerp(
+            lerp(c(0, 0, 1), c(1, 0, 1), u.x),
+            lerp(c(0, 1, 1), c(1, 1, 1), u.x),
+            u.y,
+        ),
+        u.z,
+    )
+}
+
+fn value_noise(p: Vec3, seed: u32) -> f32 {
+    lattice_noise(p, |x, y, z, _| unit_hash(x, y, z, seed) * 2.0 - 1.0)
+}
+
+fn perlin_noise(p: Vec3, seed: u32) -> f32 {
+    lattice_noise(p, |x, y, z, d| gradient(hash(x, y, z, seed), d)).clamp(-1.0, 1.0)
+}
+
+/// Stefan Gustavson's 3D simplex noise, with hashed gradients.
+fn simplex_noise(p: Vec3, seed: u32) -> f32 {
+    const F3: f32 = 1.0 / 3.0;
+    const G3: f32 = 1.0 / 6.0;
+
+    let cell = (p + (p.x + p.y + p.z) * F3).floor();
+    let d0 = p - (cell - (cell.x + cell.y + cell.z) * G3);
+    // The two middle corners of the simplex containing the point, stepping along the axes from
+    // largest to smallest offset.
+    let (o1, o2) = if d0.x >= d0.y {
+        if d0.y >= d0.z {
+            (Vec3::X, Vec3::new(1.0, 1.0, 0.0))
+        } else if d0.x >= d0.z {
+            (Vec3::X, Vec3::new(1.0, 0.0, 1.0))
+        } else {
+            (Vec3::Z, Vec3::new(1.0, 0.0, 1.0))
+        }
+    } else if d0.y < d0.z {
+        (Vec3::Z, Vec3::new(0.0, 1.0, 1.0))
+    } else if d0.x < d0.z {
+        (Vec3::Y, Vec3::new(0.0, 1.0, 1.0))
+    } else {
+        (Vec3::Y, Vec3::new(1.0, 1.0, 0.0))
+    };
+
+    let total: f32 = [Vec3::ZERO, o1, o2, Vec3::ONE]
+        .into_iter()
+        .enumerate()
+        .map(|(i, offset)| {
+            let d = d0 - offset + G3 * i as f32;
+            let falloff = 0.6 - d.length_squared();
+            if falloff <= 0.0 {
+                return 0.0;
+            }
+            let corner = cell + offset;
+            let hash = hash(corner.x as i32, corner.y as i32, corner.z as i32, seed);
+            falloff.powi(4) * gradient(hash, d)
+        })
+        .sum();
+    (32.0 * total).clamp(-1.0, 1.0)
+}
@@ -0,0 +1,289 @@
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+use bevy_reflect::{FromReflect, GetTypeRegistration, TypePath};
+
+use crate::{
+    dynamic_property::ReflectVariableProperty,
+    interval_property::{
+        IntervalProperty, IntervalPropertyComponent, PauseIntervalProperty, PauseMode,
+        PropertyOutput, PropertyTimeScale,
+    },
+    noise_property::NoiseKind,
+    prop_distribution::PropDistribution,
+    prop_sequence::SequenceMode,
+    property_binding::PropertyBinding,
+    property_target::{PropertyTargetMode, PropertyTargetOf, PropertyTargets},
+    rng::PropertyRngSeed,
+    variable_property::VariableProperty,
+};
+
+/// Registers the crate's reflect types and sets the schedule used by
+/// [IntervalPropertyAppExt::register_interval_property].
+///
+/// Registrations made before the plugin is added wait for it, and are added to its schedule when
+/// it's built.
+pub struct VariablePropertyPlugin {
+    /// The schedule [IntervalPropertyComponent] systems are added to by default.
+    pub schedule: InternedScheduleLabel,
+}
+
+impl VariablePropertyPlugin {
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+/// Runs the systems in [Update].
+impl Default for VariablePropertyPlugin {
+    fn default() -> Self {
+        Self::new(Update)
+    }
+}
+
+impl Plugin for VariablePropertyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PropertyRngSeed>()
+            .register_type::<PropDistribution>()
+            .register_type::<NoiseKind>()
+            .register_type::<PauseMode>()
+            .register_type::<PropertyTimeScale>()
+            .register_type::<PropertyTargetMode>()
+            .register_type::<PropertyTargetOf>()
+            .register_type::<PropertyTargets>()
+            .register_type::<SequenceMode>()
+            .insert_resource(IntervalPropertySchedule(self.schedule));
+        if let Some(pending) = app.world_mut().remove_resource::<PendingRegistrations>() {
+            for register in pending.0 {
+                register(app, self.schedule);
+            }
+        }
+    }
+}
+
+/// The schedule [IntervalPropertyComponent] systems are added to by
+/// [IntervalPropertyAppExt::register_interval_property], set by [VariablePropertyPlugin].
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct IntervalPropertySchedule(pub InternedScheduleLabel);
+
+/// Registrations waiting for [VariablePropertyPlugin] to choose their schedule.
+#[derive(Resource, Default)]
+struct PendingRegistrations(Vec<fn(&mut App, InternedScheduleLabel)>);
+
+/// The set every [IntervalPropertyComponent] system registered through [IntervalPropertyAppExt]
+/// runs in, to order other systems against.
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct IntervalPropertySystems;
+
+pub trait IntervalPropertyAppExt {
+    /// Adds [IntervalPropertyComponent::system] for `T` to the schedule chosen in
+    /// [VariablePropertyPlugin], and registers the reflect types used by `T`.
+    ///
+    /// If the plugin hasn't been added yet, this waits until it is, so nothing is registered
+    /// without it. Use [IntervalPropertyAppExt::register_interval_property_in] to skip the plugin.
+    fn register_interval_property<T>(&mut self) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration;
+
+    /// Same as [IntervalPropertyAppExt::register_interval_property], but adds the system to the
+    /// given schedule.
+    fn register_interval_property_in<T>(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration;
+
+    /// Same as [IntervalPropertyAppExt::register_interval_property_in], but ticks with the given
+    /// clock, ie: `Time<Real>` for UI that keeps animating while the game is paused. See
+    /// [IntervalPropertyComponent::system_with_clock].
+    fn register_interval_property_with_clock<T, C>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        C: Default + Send + Sync + 'static;
+
+    /// Same as [IntervalPropertyAppExt::register_interval_property], but adds
+    /// [IntervalPropertyComponent::system_with_events], so each generated value also sends
+    /// [PropertyRegenerated](crate::interval_property::PropertyRegenerated).
+    fn register_interval_property_with_events<T>(&mut self) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        PropertyOutput<T>: Clone + Send + Sync;
+
+    /// Same as [IntervalPropertyAppExt::register_interval_property_with_clock], but adds
+    /// [IntervalPropertyComponent::system_with_clock_and_events].
+    fn register_interval_property_with_clock_and_events<T, C>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        PropertyOutput<T>: Clone + Send + Sync,
+        C: Default + Send + Sync + 'static;
+
+    /// Adds [PropertyBinding::system] to the schedule chosen in [VariablePropertyPlugin], waiting
+    /// for the plugin like [IntervalPropertyAppExt::register_interval_property], and registers
+    /// [PropertyBinding] so it can be added from scenes. The bound properties must be registered with
+    /// [IntervalPropertyAppExt::register_variable_property] to be loaded from scenes.
+    fn register_property_binding(&mut self) -> &mut Self;
+
+    /// Same as [IntervalPropertyAppExt::register_property_binding], but adds the system to the
+    /// given schedule.
+    fn register_property_binding_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+
+    /// Registers `P` with [ReflectVariableProperty], so tools can sample it by type path, and it
+    /// can be wrapped in a [DynamicProperty](crate::dynamic_property::DynamicProperty), ie: when
+    /// loading a [PropertyBinding] from a scene.
+    fn register_variable_property<P>(&mut self) -> &mut Self
+    where
+        P: VariableProperty<Output: FromReflect>
+            + FromReflect
+            + GetTypeRegistration
+            + TypePath
+            + Clone;
+}
+
+impl IntervalPropertyAppExt for App {
+    fn register_interval_property<T>(&mut self) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+    {
+        with_plugin_schedule(self, |app, schedule| {
+            app.register_interval_property_in::<T>(schedule);
+        })
+    }
+
+    fn register_interval_property_in<T>(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+    {
+        self.register_interval_property_with_clock::<T, ()>(schedule)
+    }
+
+    fn register_interval_property_with_clock<T, C>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        C: Default + Send + Sync + 'static,
+    {
+        register_interval_property_types::<T>(self).add_systems(
+            schedule,
+            T::system_with_clock::<C>.in_set(IntervalPropertySystems),
+        )
+    }
+
+    fn register_interval_property_with_events<T>(&mut self) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        PropertyOutput<T>: Clone + Send + Sync,
+    {
+        with_plugin_schedule(self, |app, schedule| {
+            app.register_interval_property_with_clock_and_events::<T, ()>(schedule);
+        })
+    }
+
+    fn register_interval_property_with_clock_and_events<T, C>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self
+    where
+        T: IntervalPropertyComponent,
+        T::Property: GetTypeRegistration,
+        IntervalProperty<T::Property>: GetTypeRegistration,
+        PauseIntervalProperty<T>: GetTypeRegistration,
+        PropertyOutput<T>: Clone + Send + Sync,
+        C: Default + Send + Sync + 'static,
+    {
+        register_interval_property_types::<T>(self).add_systems(
+            schedule,
+            T::system_with_clock_and_events::<C>.in_set(IntervalPropertySystems),
+        )
+    }
+
+    fn register_property_binding(&mut self) -> &mut Self {
+        with_plugin_schedule(self, |app, schedule| {
+            app.register_property_binding_in(schedule);
+        })
+    }
+
+    fn register_property_binding_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.register_type::<PropertyBinding>().add_systems(
+            schedule,
+            PropertyBinding::system.in_set(IntervalPropertySystems),
+        )
+    }
+
+    fn register_variable_property<P>(&mut self) -> &mut Self
+    where
+        P: VariableProperty<Output: FromReflect>
+            + FromReflect
+            + GetTypeRegistration
+            + TypePath
+            + Clone,
+    {
+        self.register_type::<P>()
+            .register_type_data::<P, ReflectVariableProperty>()
+    }
+}
+
+fn register_interval_property_types<T>(app: &mut App) -> &mut App
+where
+    T: IntervalPropertyComponent,
+    T::Property: GetTypeRegistration,
+    IntervalProperty<T::Property>: GetTypeRegistration,
+    PauseIntervalProperty<T>: GetTypeRegistration,
+{
+    app.register_type::<T::Property>()
+        .register_type::<IntervalProperty<T::Property>>()
+        .register_type::<PauseIntervalProperty<T>>()
+}
+
+/// Runs `register` with the schedule chosen in [VariablePropertyPlugin], or once the plugin is
+/// added if it hasn't been yet.
+fn with_plugin_schedule(app: &mut App, register: fn(&mut App, InternedScheduleLabel)) -> &mut App {
+    match app.world().get_resource::<IntervalPropertySchedule>() {
+        Some(schedule) => {
+            let schedule = schedule.0;
+            register(app, schedule);
+        }
+        None => app
+            .world_mut()
+            .get_resource_or_init::<PendingRegistrations>()
+            .0
+            .push(register),
+    }
+    app
+}
@@ -0,0 +1,211 @@
+use bevy_app::PreUpdate;
+use bevy_app::{App, Plugin};
+use bevy_asset::{
+    io::Reader, Asset, AssetApp, AssetEvent, AssetId, AssetLoader, AssetTrackingSystems, Assets,
+    Handle, LoadContext,
+};
+use bevy_ecs::{component::Mutable, prelude::*};
+use bevy_reflect::TypePath;
+use rand::RngCore;
+use serde::Deserialize;
+use std::marker::PhantomData;
+
+use crate::variable_property::VariableProperty;
+
+/// A [crate::Property], [crate::interval_property::IntervalProperty] or any other deserializable
+/// generator loaded from a `.prop.ron` file.
+///
+/// Presets are sampled through a [PresetProperty], or copied into the component that ticks them
+/// with a [PresetSource], ie: for interval presets that carry their own timer.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct PropertyPreset<T: TypePath + Send + Sync> {
+    pub preset: T,
+}
+
+/// Loads `.prop.ron` files into a [PropertyPreset], using the serde format of `T`.
+#[derive(TypePath)]
+pub struct PropertyPresetLoader<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for PropertyPresetLoader<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PropertyPresetLoaderError {
+    #[error("Could not read property preset: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse property preset: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl<T> AssetLoader for PropertyPresetLoader<T>
+where
+    T: for<'de> Deserialize<'de> + TypePath + Send + Sync + 'static,
+{
+    type Asset = PropertyPreset<T>;
+    type Settings = ();
+    type Error = PropertyPresetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let preset = ron::de::from_bytes(&bytes)?;
+        Ok(PropertyPreset { preset })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["prop.ron"]
+    }
+}
+
+/// Registers [PropertyPreset] as an asset along with its loader, and adds
+/// [PresetProperty::system] to keep [PresetProperty] components up to date.
+///
+/// Add one for each preset type, ie: `PropertyPresetPlugin::<Property<f32>>::default()`.
+pub struct PropertyPresetPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for PropertyPresetPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Plugin for PropertyPresetPlugin<T>
+where
+    T: for<'de> Deserialize<'de> + TypePath + Clone + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.init_asset::<PropertyPreset<T>>()
+            .register_asset_loader(PropertyPresetLoader::<T>::default())
+            .add_systems(
+                PreUpdate,
+                PresetProperty::<T>::system.after(AssetTrackingSystems),
+            );
+    }
+}
+
+/// The ids of presets modified since the last run, from their [AssetEvent]s.
+fn modified_presets<T: TypePath + Send + Sync>(
+    events: &mut MessageReader<AssetEvent<PropertyPreset<T>>>,
+) -> Vec<AssetId<PropertyPreset<T>>> {
+    events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Component generating values from a copy of a [PropertyPreset], as a [VariableProperty] with
+/// `None` output until the preset has loaded.
+///
+/// The copy keeps its state between values, so [crate::Property::Sequence] and
+/// [NoiseProperty](crate::noise_property::NoiseProperty) presets advance with
+/// [VariableProperty::next_value]. [PresetProperty::system] copies the preset once loaded, and
+/// again when it's modified, ie: hot reloaded, which restarts that state.
+#[derive(Component, TypePath, Clone, Debug)]
+pub struct PresetProperty<T: TypePath + Send + Sync> {
+    pub handle: Handle<PropertyPreset<T>>,
+    property: Option<T>,
+    loaded: Option<AssetId<PropertyPreset<T>>>,
+}
+
+impl<T: TypePath + Clone + Send + Sync> PresetProperty<T> {
+    pub fn new(handle: Handle<PropertyPreset<T>>) -> Self {
+        Self {
+            handle,
+            property: None,
+            loaded: None,
+        }
+    }
+
+    /// The copy of the preset values are generated from, or None if it hasn't loaded yet.
+    pub fn property(&self) -> Option<&T> {
+        self.property.as_ref()
+    }
+
+    /// Copies the preset into each [PresetProperty] once it has loaded, after its handle
+    /// changes, or when it's modified.
+    pub fn system(
+        mut events: MessageReader<AssetEvent<PropertyPreset<T>>>,
+        presets: Res<Assets<PropertyPreset<T>>>,
+        mut query: Query<&mut Self>,
+    ) {
+        let modified = modified_presets(&mut events);
+        for mut preset_property in query.iter_mut() {
+            let id = preset_property.handle.id();
+            if preset_property.loaded == Some(id) && !modified.contains(&id) {
+                continue;
+            }
+            if let Some(preset) = presets.get(id) {
+                preset_property.property = Some(preset.preset.clone());
+                preset_property.loaded = Some(id);
+            }
+        }
+    }
+}
+
+impl<T> VariableProperty for PresetProperty<T>
+where
+    T: VariableProperty<Output: Send + Sync> + TypePath + Clone + Send + Sync,
+{
+    type Output = Option<T::Output>;
+
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Option<T::Output> {
+        Some(self.property.as_ref()?.get_value_with(rng))
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Option<T::Output> {
+        Some(self.property.as_mut()?.next_value_with(rng))
+    }
+}
+
+/// Copies a [PropertyPreset] into a component on the same entity once it has loaded, and again
+/// when it's modified, ie: to hot reload an
+/// [IntervalProperty](crate::interval_property::IntervalProperty) preset into an
+/// [IntervalPropertyComponent](crate::interval_property::IntervalPropertyComponent).
+///
+/// Add [PresetSource::system] for each component it copies into, ie:
+/// `app.add_systems(PreUpdate, PresetSource::<IntervalProperty<Property<f32>>>::system::<MyIntervalProperty>)`.
+#[derive(Component, Clone, Debug)]
+pub struct PresetSource<T: TypePath + Send + Sync> {
+    pub handle: Handle<PropertyPreset<T>>,
+    loaded: Option<AssetId<PropertyPreset<T>>>,
+}
+
+impl<T: TypePath + Clone + Send + Sync> PresetSource<T> {
+    pub fn new(handle: Handle<PropertyPreset<T>>) -> Self {
+        Self {
+            handle,
+            loaded: None,
+        }
+    }
+
+    /// Replaces the `C` of each entity with a [PresetSource] with a copy of its preset once it
+    /// has loaded, after its handle changes, or when it's modified.
+    pub fn system<C: Component<Mutability = Mutable> + AsMut<T>>(
+        mut events: MessageReader<AssetEvent<PropertyPreset<T>>>,
+        presets: Res<Assets<PropertyPreset<T>>>,
+        mut query: Query<(&mut Self, &mut C)>,
+    ) {
+        let modified = modified_presets(&mut events);
+        for (mut source, mut target) in query.iter_mut() {
+            let id = source.handle.id();
+            if source.loaded == Some(id) && !modified.contains(&id) {
+                continue;
+            }
+            if let Some(preset) = presets.get(id) {
+                *target.as_mut().as_mut() = preset.preset.clone();
+                source.loaded = Some(id);
+            }
+        }
+    }
+}
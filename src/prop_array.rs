@@ -1,4 +1,14 @@
 use bevy_math::*;
+use bevy_reflect::Reflect;
+use rand::RngCore;
+
+use std::ops::{Range, RangeInclusive};
+
+use crate::prop_distribution::PropDistribution;
+use crate::prop_rand::PropRand;
+use crate::prop_range::PropRange;
+use crate::property_error::PropertyError;
+use crate::Property;
 
 /// Wrapper around generic-length array for foreign trait implementations
 #[derive(Clone, Debug, Reflect)]
@@ -16,6 +26,8 @@ impl<T, const N: usize> From<[T; N]> for PropArray<T, N> {
     }
 }
 
+// From<PropArray<T, N>> for [T; N] isn't allowed by the orphan rules.
+#[allow(clippy::from_over_into)]
 impl<T, const N: usize> Into<[T; N]> for PropArray<T, N> {
     fn into(self) -> [T; N] {
         self.0
@@ -34,11 +46,102 @@ impl<T, const N: usize> AsMut<[T]> for PropArray<T, N> {
     }
 }
 
+impl<T, const N: usize> PropRand for PropArray<T, N>
+where
+    T: PropRand + Clone,
+{
+    fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self(<[T; N]>::gen(rng))
+    }
+
+    fn gen_range<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+        Self(<[T; N]>::gen_range(
+            rng,
+            PropRange::new(range.start.0, range.end.0, range.inclusive),
+        ))
+    }
+
+    fn gen_distribution<R: RngCore + ?Sized>(
+        rng: &mut R,
+        range: PropRange<Self>,
+        distribution: &PropDistribution,
+    ) -> Self {
+        Self(<[T; N]>::gen_distribution(
+            rng,
+            PropRange::new(range.start.0, range.end.0, range.inclusive),
+            distribution,
+        ))
+    }
+
+    fn from_units<F: FnMut() -> f64>(range: PropRange<Self>, units: &mut F) -> Option<Self> {
+        <[T; N]>::from_units(
+            PropRange::new(range.start.0, range.end.0, range.inclusive),
+            units,
+        )
+        .map(Self)
+    }
+
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+        Some(Self(<[T; N]>::interpolate(&from.0, &to.0, t)?))
+    }
+
+    fn validate_bounds(start: &Self, end: &Self, inclusive: bool) -> Result<(), PropertyError> {
+        <[T; N]>::validate_bounds(&start.0, &end.0, inclusive)
+    }
+
+    fn fix_bounds(start: &mut Self, end: &mut Self) {
+        <[T; N]>::fix_bounds(&mut start.0, &mut end.0);
+    }
+}
+
+impl<T, const N: usize> From<PropArray<T, N>> for Property<PropArray<T, N>> {
+    fn from(v: PropArray<T, N>) -> Self {
+        Property::Static(v)
+    }
+}
+
+impl<T, const N: usize> From<Range<PropArray<T, N>>> for Property<PropArray<T, N>> {
+    fn from(v: Range<PropArray<T, N>>) -> Self {
+        Property::RandomRange(PropRange::new(v.start, v.end, false))
+    }
+}
+
+impl<T, const N: usize> From<RangeInclusive<PropArray<T, N>>> for Property<PropArray<T, N>> {
+    fn from(v: RangeInclusive<PropArray<T, N>>) -> Self {
+        let (start, end) = v.into_inner();
+        Property::RandomRange(PropRange::new(start, end, true))
+    }
+}
+
+impl<T, const N: usize> From<Vec<PropArray<T, N>>> for Property<PropArray<T, N>> {
+    fn from(v: Vec<PropArray<T, N>>) -> Self {
+        Property::RandomChoice(v)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[PropArray<T, N>]> for Property<PropArray<T, N>> {
+    fn from(v: &[PropArray<T, N>]) -> Self {
+        Property::RandomChoice(v.to_vec())
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<[PropArray<T, N>; M]> for Property<PropArray<T, N>> {
+    fn from(v: [PropArray<T, N>; M]) -> Self {
+        Property::RandomChoice(v.into())
+    }
+}
+
 macro_rules! prop_array_into_impl {
     ($vec_type:tt, $element_type:tt, $dim:literal) => {
-        impl Into<$vec_type> for PropArray<$element_type, $dim> {
-            fn into(self) -> $vec_type {
-                self.0.into()
+        impl From<PropArray<$element_type, $dim>> for $vec_type {
+            fn from(v: PropArray<$element_type, $dim>) -> Self {
+                v.0.into()
+            }
+        }
+
+        impl From<$vec_type> for PropArray<$element_type, $dim> {
+            fn from(v: $vec_type) -> Self {
+                Self(v.into())
             }
         }
     };
@@ -62,4 +165,50 @@ prop_array_into_impl!(IVec4, i32, 4);
 
 prop_array_into_impl!(Vec3A, f32, 3);
 
-prop_array_into_impl!(Color, f32, 4);
+/// Converts between `PropArray<f32, 4>` and the color's own components, with alpha last.
+#[cfg(feature = "color")]
+macro_rules! prop_array_color_impl {
+    ($color_type:tt) => {
+        impl From<PropArray<f32, 4>> for bevy_color::$color_type {
+            fn from(v: PropArray<f32, 4>) -> Self {
+                bevy_color::ColorToComponents::from_f32_array(v.0)
+            }
+        }
+
+        impl From<bevy_color::$color_type> for PropArray<f32, 4> {
+            fn from(v: bevy_color::$color_type) -> Self {
+                Self(bevy_color::ColorToComponents::to_f32_array(v))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "color")]
+mod color_impls {
+    use super::PropArray;
+
+    prop_array_color_impl!(Srgba);
+    prop_array_color_impl!(LinearRgba);
+    prop_array_color_impl!(Hsla);
+    prop_array_color_impl!(Hsva);
+    prop_array_color_impl!(Hwba);
+    prop_array_color_impl!(Laba);
+    prop_array_color_impl!(Lcha);
+    prop_array_color_impl!(Oklaba);
+    prop_array_color_impl!(Oklcha);
+    prop_array_color_impl!(Xyza);
+
+    /// Treats the array as [bevy_color::LinearRgba]
+    impl From<PropArray<f32, 4>> for bevy_color::Color {
+        fn from(v: PropArray<f32, 4>) -> Self {
+            bevy_color::LinearRgba::from(v).into()
+        }
+    }
+
+    /// Converts the color to [bevy_color::LinearRgba] first
+    impl From<bevy_color::Color> for PropArray<f32, 4> {
+        fn from(v: bevy_color::Color) -> Self {
+            v.to_linear().into()
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use bevy_math::curve::Curve;
+use bevy_reflect::Reflect;
+use rand::{Rng, RngCore};
+
+use std::f64::consts::TAU;
+
+use crate::property_error::PropertyError;
+
+/// Largest f64 below 1.0, used to keep samples of exclusive ranges below the end.
+const ONE_BELOW: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// How many times a truncated normal is resampled before falling back to clamping.
+const NORMAL_MAX_ATTEMPTS: usize = 16;
+
+/// The shape of the distribution used to sample a [crate::prop_range::PropRange].
+///
+/// All parameters are relative to the range, where 0.0 is the start and 1.0 is the end, so one
+/// distribution can be used for any [crate::prop_rand::PropRand] type. Vectors, arrays and
+/// tuples sample each element independently.
+#[derive(Clone, Debug, Default, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PropDistribution {
+    /// Every value in the range is equally likely.
+    #[default]
+    Uniform,
+
+    /// Normal distribution truncated to the range.
+    Normal { mean: f32, std_dev: f32 },
+
+    /// Triangular distribution peaking at `mode`.
+    Triangular { mode: f32 },
+
+    /// Exponential distribution truncated to the range. Positive rates skew towards the start,
+    /// negative rates skew towards the end.
+    Exponential { rate: f32 },
+
+    /// A user-supplied cumulative distribution function, given as its values at evenly spaced
+    /// points from the start to the end of the range. The values must be non-decreasing.
+    Cdf(Vec<f32>),
+}
+
+impl PropDistribution {
+    /// Builds a [PropDistribution::Cdf] by sampling `cdf` at `samples` evenly spaced points in
+    /// 0.0..=1.0.
+    pub fn from_cdf(cdf: impl Fn(f32) -> f32, samples: usize) -> Self {
+        let last = samples.max(2) - 1;
+        Self::Cdf((0..=last).map(|i| cdf(i as f32 / last as f32)).collect())
+    }
+
+    /// Builds a [PropDistribution::Cdf] by sampling `curve` at `samples` evenly spaced points over
+    /// its domain. The curve's domain must be bounded.
+    pub fn from_cdf_curve(curve: &impl Curve<f32>, samples: usize) -> Self {
+        let domain = curve.domain();
+        Self::from_cdf(
+            |t| curve.sample_clamped(domain.start() + t * domain.length()),
+            samples,
+        )
+    }
+
+    /// Checks that the parameters can be sampled from, so that distributions loaded from files can
+    /// be rejected instead of sampling unexpected values. See [crate::Property::validate].
+    ///
+    /// Exponential rates of zero or below are allowed, as they skew towards the end.
+    pub fn validate(&self) -> Result<(), PropertyError> {
+        match self {
+            PropDistribution::Uniform => Ok(()),
+            PropDistribution::Normal { mean, std_dev } => {
+                if !mean.is_finite() || !std_dev.is_finite() {
+                    Err(PropertyError::NonFiniteParameter)
+                } else if *std_dev <= 0.0 {
+                    Err(PropertyError::NonPositiveStdDev)
+                } else {
+                    Ok(())
+                }
+            }
+            PropDistribution::Triangular { mode } => {
+                if !mode.is_finite() {
+                    Err(PropertyError::NonFiniteParameter)
+                } else if !(0.0..=1.0).contains(mode) {
+                    Err(PropertyError::ModeOutOfRange)
+                } else {
+                    Ok(())
+                }
+            }
+            PropDistribution::Exponential { rate } if !rate.is_finite() => {
+                Err(PropertyError::NonFiniteParameter)
+            }
+            PropDistribution::Exponential { .. } => Ok(()),
+            PropDistribution::Cdf(cdf) => {
+                if cdf.len() < 2 {
+                    Err(PropertyError::EmptyCdf)
+                } else if cdf.iter().any(|v| !v.is_finite()) {
+                    Err(PropertyError::NonFiniteParameter)
+                } else if cdf.windows(2).any(|pair| pair[1] < pair[0]) {
+                    Err(PropertyError::DecreasingCdf)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Samples a value in 0.0..=1.0, or 0.0..1.0 if `inclusive` is false.
+    pub fn sample_unit<R: RngCore + ?Sized>(&self, rng: &mut R, inclusive: bool) -> f64 {
+        let t = match self {
+            PropDistribution::Uniform => {
+                if inclusive {
+                    rng.gen_range(0.0..=1.0)
+                } else {
+                    rng.gen_range(0.0..1.0)
+                }
+            }
+            PropDistribution::Normal { mean, std_dev } => {
+                let (mean, std_dev) = (*mean as f64, *std_dev as f64);
+                let mut t = mean;
+                for _ in 0..NORMAL_MAX_ATTEMPTS {
+                    t = mean + std_dev * standard_normal(rng);
+                    if (0.0..=1.0).contains(&t) {
+                        break;
+                    }
+                }
+                t
+            }
+            PropDistribution::Triangular { mode } => {
+                let mode = (*mode as f64).clamp(0.0, 1.0);
+                let u: f64 = rng.gen();
+                if u < mode {
+                    (u * mode).sqrt()
+                } else {
+                    1.0 - ((1.0 - u) * (1.0 - mode)).sqrt()
+                }
+            }
+            PropDistribution::Exponential { rate } => {
+                let rate = *rate as f64;
+                let u: f64 = rng.gen();
+                if rate == 0.0 {
+                    u
+                } else {
+                    -(1.0 - u * (1.0 - (-rate).exp())).ln() / rate
+                }
+            }
+            PropDistribution::Cdf(cdf) => sample_cdf(cdf, rng.gen()),
+        };
+        let t = t.clamp(0.0, 1.0);
+        if inclusive {
+            t
+        } else {
+            t.min(ONE_BELOW)
+        }
+    }
+}
+
+/// Box-Muller transform
+fn standard_normal<R: RngCore + ?Sized>(rng: &mut R) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+}
+
+/// Inverts the piecewise-linear CDF at `u`.
+fn sample_cdf(cdf: &[f32], u: f64) -> f64 {
+    let (Some(first), Some(last)) = (cdf.first(), cdf.last()) else {
+        return u;
+    };
+    let (first, last) = (*first as f64, *last as f64);
+    if cdf.len() < 2 || last <= first {
+        return u;
+    }
+    let target = first + u * (last - first);
+    let idx = cdf
+        .partition_point(|v| (*v as f64) <= target)
+        .clamp(1, cdf.len() - 1);
+    let (lo, hi) = (cdf[idx - 1] as f64, cdf[idx] as f64);
+    let frac = if hi > lo {
+        (target - lo) / (hi - lo)
+    } else {
+        0.0
+    };
+    (idx as f64 - 1.0 + frac) / (cdf.len() - 1) as f64
+}
@@ -2,9 +2,16 @@ use bevy_math::*;
 
 use array_macro::array;
 
+use std::f32::consts::TAU;
+
 use rand::{Rng, RngCore};
 
+use crate::prop_distribution::PropDistribution;
 use crate::prop_range::PropRange;
+use crate::prop_shape::PropShape;
+use crate::property_error::PropertyError;
+
+pub use bevy_variable_property_macros::PropRand;
 
 /// Rand trait to allow defining of random generation for foreign types.
 ///
@@ -16,10 +23,104 @@ pub trait PropRand {
     fn gen_range<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self
     where
         Self: Sized;
+
+    /// Generates a value within the range, shaped by the given distribution.
+    ///
+    /// Defaults to [PropRand::gen_range] for [PropDistribution::Uniform], and to mapping unit
+    /// samples with [PropRand::from_units] for the others.
+    ///
+    /// # Panics
+    /// If the distribution isn't uniform and the type doesn't implement [PropRand::from_units].
+    fn gen_distribution<R: RngCore + ?Sized>(
+        rng: &mut R,
+        range: PropRange<Self>,
+        distribution: &PropDistribution,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        match distribution {
+            PropDistribution::Uniform => Self::gen_range(rng, range),
+            _ => {
+                let inclusive = range.inclusive;
+                Self::from_units(range, &mut || distribution.sample_unit(rng, inclusive))
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "cannot map {} from unit values",
+                            std::any::type_name::<Self>()
+                        )
+                    })
+            }
+        }
+    }
+
+    /// Maps values in 0.0..=1.0 into the range, pulling one from `units` for each component in
+    /// order. Also used to turn noise into values, see [crate::noise_property::NoiseProperty].
+    ///
+    /// None if the type doesn't support it, which is the default.
+    fn from_units<F: FnMut() -> f64>(_range: PropRange<Self>, _units: &mut F) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Returns `from` at `t` of 0.0 and `to` at 1.0, per component for composite types. Used to
+    /// ease between values, see [IntervalProperty::set_transition].
+    ///
+    /// None if the type can't be interpolated, which is the default.
+    ///
+    /// [IntervalProperty::set_transition]: crate::interval_property::IntervalProperty::set_transition
+    fn interpolate(_from: &Self, _to: &Self, _t: f32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Checks that values can be generated between the bounds, per component for composite types.
+    /// See [crate::Property::validate].
+    fn validate_bounds(_start: &Self, _end: &Self, _inclusive: bool) -> Result<(), PropertyError>
+    where
+        Self: Sized,
+    {
+        Ok(())
+    }
+
+    /// Swaps the bounds if the start is after the end, per component for composite types. See
+    /// [crate::Property::fix_reversed_bounds].
+    fn fix_bounds(_start: &mut Self, _end: &mut Self)
+    where
+        Self: Sized,
+    {
+    }
+
+    /// Samples a point inside the shape, or on its boundary if `boundary` is true.
+    ///
+    /// Only points can be sampled from shapes, Vec2 from 2D shapes and Vec3 from any shape. Other
+    /// types fail with [PropertyError::UnsupportedShape], which is the default.
+    fn gen_shape<R: RngCore + ?Sized>(
+        _rng: &mut R,
+        _shape: &PropShape,
+        _boundary: bool,
+    ) -> Result<Self, PropertyError>
+    where
+        Self: Sized,
+    {
+        Err(PropertyError::UnsupportedShape)
+    }
+
+    /// Checks that [PropRand::gen_shape] can sample the shape, see [crate::Property::validate].
+    fn validate_shape(_shape: &PropShape) -> Result<(), PropertyError>
+    where
+        Self: Sized,
+    {
+        Err(PropertyError::UnsupportedShape)
+    }
 }
 
 macro_rules! prop_rand_impl {
-    ($type:tt) => {
+    ($type:tt, $kind:ident) => {
         impl PropRand for $type {
             fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
                 rng.gen()
@@ -32,35 +133,184 @@ macro_rules! prop_rand_impl {
                     rng.gen_range(range.start..range.end)
                 }
             }
+
+            fn gen_distribution<R: RngCore + ?Sized>(
+                rng: &mut R,
+                range: PropRange<$type>,
+                distribution: &PropDistribution,
+            ) -> Self {
+                prop_rand_distribution_impl!($kind, $type, rng, range, distribution)
+            }
+
+            fn from_units<F: FnMut() -> f64>(
+                range: PropRange<$type>,
+                units: &mut F,
+            ) -> Option<Self> {
+                Some(prop_rand_unit_impl!(
+                    $kind,
+                    $type,
+                    range,
+                    units().clamp(0.0, 1.0)
+                ))
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                Some(prop_rand_interpolate_impl!($kind, $type, from, to, t))
+            }
+
+            fn validate_bounds(
+                start: &$type,
+                end: &$type,
+                inclusive: bool,
+            ) -> Result<(), PropertyError> {
+                prop_rand_validate_impl!($kind, start, end);
+                if start > end {
+                    Err(PropertyError::ReversedBounds)
+                } else if start == end && !inclusive {
+                    Err(PropertyError::EmptyRange)
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn fix_bounds(start: &mut $type, end: &mut $type) {
+                if start > end {
+                    std::mem::swap(start, end);
+                }
+            }
         }
     };
 }
 
+// Integers round to the nearest value.
+macro_rules! prop_rand_interpolate_impl {
+    (int, $type:tt, $from:ident, $to:ident, $t:ident) => {{
+        let (from, to) = (*$from as f64, *$to as f64);
+        (from + (to - from) * $t as f64).round() as $type
+    }};
+    (float, $type:tt, $from:ident, $to:ident, $t:ident) => {
+        $from + ($to - $from) * $t as $type
+    };
+}
+
+macro_rules! prop_rand_validate_impl {
+    (int, $start:ident, $end:ident) => {};
+    (float, $start:ident, $end:ident) => {
+        if !$start.is_finite() || !$end.is_finite() || !($end - $start).is_finite() {
+            return Err(PropertyError::NonFiniteBound);
+        }
+    };
+}
+
+macro_rules! prop_rand_distribution_impl {
+    (int, $type:tt, $rng:ident, $range:ident, $distribution:ident) => {
+        prop_rand_unit_impl!(int, $type, $range, $distribution.sample_unit($rng, false))
+    };
+    (float, $type:tt, $rng:ident, $range:ident, $distribution:ident) => {
+        prop_rand_unit_impl!(
+            float,
+            $type,
+            $range,
+            $distribution.sample_unit($rng, $range.inclusive)
+        )
+    };
+}
+
+// Integers map the unit value onto one of the span's buckets, floats interpolate directly.
+macro_rules! prop_rand_unit_impl {
+    (int, $type:tt, $range:ident, $t:expr) => {{
+        let span =
+            $range.end as f64 - $range.start as f64 + if $range.inclusive { 1.0 } else { 0.0 };
+        assert!(span > 0.0, "cannot sample empty range");
+        // A unit value of exactly 1.0 would land one past the last bucket.
+        let offset = ($t * span).min(span - 1.0) as u128;
+        // Offsets can be larger than the signed type's max, wrapping brings them back in range.
+        $range.start.wrapping_add(offset as $type)
+    }};
+    (float, $type:tt, $range:ident, $t:expr) => {{
+        assert!(
+            $range.start < $range.end || ($range.inclusive && $range.start == $range.end),
+            "cannot sample empty range"
+        );
+        let t = $t as $type;
+        $range.start + (($range.end - $range.start) * t)
+    }};
+}
+
 macro_rules! prop_rand_impl_many {
-    ($($type:tt,)+) => {
+    ($kind:ident: $($type:tt,)+) => {
         $(
-            prop_rand_impl!($type);
+            prop_rand_impl!($type, $kind);
         )+
     };
 }
 
-prop_rand_impl_many!(usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64,);
+prop_rand_impl_many!(int: usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128,);
+prop_rand_impl_many!(float: f32, f64,);
 
 impl<T, const N: usize> PropRand for [T; N]
 where
     T: PropRand + Clone,
 {
     fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
-        array![_ => T::gen(rng); N].into()
+        array![_ => T::gen(rng); N]
     }
 
     fn gen_range<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<[T; N]>) -> Self {
         //Seems like it could be sluggish sampling each time, there might be a better way to implement this.
         if range.inclusive {
-            array![i => T::gen_range(rng, (range.start[i].clone()..=range.end[i].clone()).into()); N].into()
+            array![i => T::gen_range(rng, (range.start[i].clone()..=range.end[i].clone()).into()); N]
         } else {
             array![i => T::gen_range(rng, (range.start[i].clone()..range.end[i].clone()).into()); N]
-                .into()
+        }
+    }
+
+    fn gen_distribution<R: RngCore + ?Sized>(
+        rng: &mut R,
+        range: PropRange<[T; N]>,
+        distribution: &PropDistribution,
+    ) -> Self {
+        array![i => T::gen_distribution(
+            rng,
+            PropRange::new(range.start[i].clone(), range.end[i].clone(), range.inclusive),
+            distribution
+        ); N]
+    }
+
+    fn from_units<F: FnMut() -> f64>(range: PropRange<[T; N]>, units: &mut F) -> Option<Self> {
+        let values = (0..N)
+            .map(|i| {
+                T::from_units(
+                    PropRange::new(
+                        range.start[i].clone(),
+                        range.end[i].clone(),
+                        range.inclusive,
+                    ),
+                    units,
+                )
+            })
+            .collect::<Option<Vec<T>>>()?;
+        values.try_into().ok()
+    }
+
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+        let mut value = from.clone();
+        for ((value, from), to) in value.iter_mut().zip(from).zip(to) {
+            *value = T::interpolate(from, to, t)?;
+        }
+        Some(value)
+    }
+
+    fn validate_bounds(start: &Self, end: &Self, inclusive: bool) -> Result<(), PropertyError> {
+        start
+            .iter()
+            .zip(end)
+            .try_for_each(|(start, end)| T::validate_bounds(start, end, inclusive))
+    }
+
+    fn fix_bounds(start: &mut Self, end: &mut Self) {
+        for (start, end) in start.iter_mut().zip(end) {
+            T::fix_bounds(start, end);
         }
     }
 }
@@ -77,6 +327,30 @@ macro_rules! prop_rand_tuple_impls_inner {
     }
 }
 
+macro_rules! prop_rand_tuple_impls_inner_4 {
+    () => {};
+    ($range:ident, $rng:ident, $distribution:ident, [$($list_idx:literal $list:tt,)*], $head_idx:literal $head:tt, $($tail_idx:literal $tail:tt,)*) => {
+       prop_rand_tuple_impls_inner_4!($range, $rng, $distribution, [$head_idx $head, $($list_idx $list,)*], $($tail_idx $tail,)*)
+    };
+    ($range:ident, $rng:ident, $distribution:ident, [$($idx:literal $list:tt,)+],) => {
+        paste::paste! {(
+            $($list::gen_distribution($rng, PropRange { start: $range.start.$idx, end: $range.end.$idx, inclusive: $range.inclusive }, $distribution),)+
+        )}
+    }
+}
+
+macro_rules! prop_rand_tuple_impls_inner_5 {
+    () => {};
+    ($range:ident, $units:ident, [$($list_idx:literal $list:tt,)*], $head_idx:literal $head:tt, $($tail_idx:literal $tail:tt,)*) => {
+       prop_rand_tuple_impls_inner_5!($range, $units, [$head_idx $head, $($list_idx $list,)*], $($tail_idx $tail,)*)
+    };
+    ($range:ident, $units:ident, [$($idx:literal $list:tt,)+],) => {
+        paste::paste! {(
+            $($list::from_units(PropRange { start: $range.start.$idx, end: $range.end.$idx, inclusive: $range.inclusive }, $units)?,)+
+        )}
+    }
+}
+
 macro_rules! prop_rand_tuple_impls_inner_2 {
     () => {};
     ([$($type_in_list:tt,)*], $head_type:tt, $($tail:tt,)*) => {
@@ -113,6 +387,38 @@ macro_rules! prop_rand_tuple_impls {
                 prop_rand_tuple_impls_inner!(range, rng, [], $head_idx $head, $($tail_idx $tail,)*)
 
             }
+
+            fn gen_distribution<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<prop_rand_tuple_impls_inner_2!([], $head, $($tail,)*)>, distribution: &PropDistribution) -> Self {
+                prop_rand_tuple_impls_inner_4!(range, rng, distribution, [], $head_idx $head, $($tail_idx $tail,)*)
+            }
+
+            fn from_units<Units: FnMut() -> f64>(range: PropRange<prop_rand_tuple_impls_inner_2!([], $head, $($tail,)*)>, units: &mut Units) -> Option<Self> {
+                Some(prop_rand_tuple_impls_inner_5!(range, units, [], $head_idx $head, $($tail_idx $tail,)*))
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                let mut value = from.clone();
+                paste::paste! {
+                    value.$head_idx = $head::interpolate(&from.$head_idx, &to.$head_idx, t)?;
+                    $(value.$tail_idx = $tail::interpolate(&from.$tail_idx, &to.$tail_idx, t)?;)*
+                }
+                Some(value)
+            }
+
+            fn validate_bounds(start: &Self, end: &Self, inclusive: bool) -> Result<(), PropertyError> {
+                paste::paste! {
+                    $head::validate_bounds(&start.$head_idx, &end.$head_idx, inclusive)?;
+                    $($tail::validate_bounds(&start.$tail_idx, &end.$tail_idx, inclusive)?;)*
+                }
+                Ok(())
+            }
+
+            fn fix_bounds(start: &mut Self, end: &mut Self) {
+                paste::paste! {
+                    $head::fix_bounds(&mut start.$head_idx, &mut end.$head_idx);
+                    $($tail::fix_bounds(&mut start.$tail_idx, &mut end.$tail_idx);)*
+                }
+            }
         }
 
         prop_rand_tuple_impls!($($tail_idx $tail,)*);
@@ -130,7 +436,7 @@ prop_rand_tuple_impls!(15 P, 14 O, 13 N, 12 M, 11 L, 10 K, 9 J, 8 I, 7 H, 6 G, 5
 // the rng once (I think?) and that could make the first impl below the most performant
 // potentially.
 macro_rules! prop_rand_vec_impl {
-    ($vec_type:tt, $inner_type:tt, $size:literal) => {
+    ($vec_type:tt, $inner_type:tt, $size:literal $(, $gen_shape:expr, $validate_shape:expr)?) => {
         impl PropRand for $vec_type {
             fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
                 <[$inner_type; $size]>::gen(rng).into()
@@ -147,12 +453,77 @@ macro_rules! prop_rand_vec_impl {
                 )
                 .into()
             }
+
+            fn gen_distribution<R: RngCore + ?Sized>(
+                rng: &mut R,
+                range: PropRange<Self>,
+                distribution: &PropDistribution,
+            ) -> Self {
+                <[$inner_type; $size]>::gen_distribution(
+                    rng,
+                    PropRange {
+                        start: range.start.into(),
+                        end: range.end.into(),
+                        inclusive: range.inclusive,
+                    },
+                    distribution,
+                )
+                .into()
+            }
+
+            fn from_units<F: FnMut() -> f64>(
+                range: PropRange<Self>,
+                units: &mut F,
+            ) -> Option<Self> {
+                <[$inner_type; $size]>::from_units(
+                    PropRange {
+                        start: range.start.into(),
+                        end: range.end.into(),
+                        inclusive: range.inclusive,
+                    },
+                    units,
+                )
+                .map(Into::into)
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                <[$inner_type; $size]>::interpolate(&(*from).into(), &(*to).into(), t)
+                    .map(Into::into)
+            }
+
+            fn validate_bounds(
+                start: &Self,
+                end: &Self,
+                inclusive: bool,
+            ) -> Result<(), PropertyError> {
+                <[$inner_type; $size]>::validate_bounds(&(*start).into(), &(*end).into(), inclusive)
+            }
+
+            fn fix_bounds(start: &mut Self, end: &mut Self) {
+                let (mut start_array, mut end_array) = ((*start).into(), (*end).into());
+                <[$inner_type; $size]>::fix_bounds(&mut start_array, &mut end_array);
+                (*start, *end) = (start_array.into(), end_array.into());
+            }
+
+            $(
+                fn gen_shape<R: RngCore + ?Sized>(
+                    rng: &mut R,
+                    shape: &PropShape,
+                    boundary: bool,
+                ) -> Result<Self, PropertyError> {
+                    $gen_shape(shape, rng, boundary)
+                }
+
+                fn validate_shape(shape: &PropShape) -> Result<(), PropertyError> {
+                    $validate_shape(shape)
+                }
+            )?
         }
     };
 }
 
-prop_rand_vec_impl!(Vec2, f32, 2);
-prop_rand_vec_impl!(Vec3, f32, 3);
+prop_rand_vec_impl!(Vec2, f32, 2, PropShape::sample_2d, PropShape::validate_2d);
+prop_rand_vec_impl!(Vec3, f32, 3, PropShape::sample, PropShape::validate);
 prop_rand_vec_impl!(Vec4, f32, 4);
 
 prop_rand_vec_impl!(DVec2, f64, 2);
@@ -191,6 +562,242 @@ impl PropRand for Rect {
             ),
         }
     }
+    fn gen_distribution<R: RngCore + ?Sized>(
+        rng: &mut R,
+        range: PropRange<Self>,
+        distribution: &PropDistribution,
+    ) -> Self {
+        Rect {
+            min: Vec2::gen_distribution(
+                rng,
+                PropRange::new(range.start.min, range.end.min, range.inclusive),
+                distribution,
+            ),
+            max: Vec2::gen_distribution(
+                rng,
+                PropRange::new(range.start.max, range.end.max, range.inclusive),
+                distribution,
+            ),
+        }
+    }
+    fn from_units<F: FnMut() -> f64>(range: PropRange<Self>, units: &mut F) -> Option<Self> {
+        Some(Rect {
+            min: Vec2::from_units(
+                PropRange::new(range.start.min, range.end.min, range.inclusive),
+                units,
+            )?,
+            max: Vec2::from_units(
+                PropRange::new(range.start.max, range.end.max, range.inclusive),
+                units,
+            )?,
+        })
+    }
+    fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+        Some(Rect {
+            min: Vec2::interpolate(&from.min, &to.min, t)?,
+            max: Vec2::interpolate(&from.max, &to.max, t)?,
+        })
+    }
+    fn validate_bounds(start: &Self, end: &Self, inclusive: bool) -> Result<(), PropertyError> {
+        Vec2::validate_bounds(&start.min, &end.min, inclusive)?;
+        Vec2::validate_bounds(&start.max, &end.max, inclusive)
+    }
+    fn fix_bounds(start: &mut Self, end: &mut Self) {
+        Vec2::fix_bounds(&mut start.min, &mut end.min);
+        Vec2::fix_bounds(&mut start.max, &mut end.max);
+    }
+}
+
+/// Rotations and directions: Random is uniformly distributed over every orientation, ranges pick a
+/// point along the slerp path from the start to the end with an evenly spread angle.
+macro_rules! prop_rand_rotation_impl {
+    ($type:tt, $gen:expr, $slerp:expr) => {
+        impl PropRand for $type {
+            fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                $gen(rng)
+            }
+
+            fn gen_range<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                Self::gen_distribution(rng, range, &PropDistribution::Uniform)
+            }
+
+            fn gen_distribution<R: RngCore + ?Sized>(
+                rng: &mut R,
+                range: PropRange<Self>,
+                distribution: &PropDistribution,
+            ) -> Self {
+                let t = distribution.sample_unit(rng, range.inclusive) as f32;
+                $slerp(range.start, range.end, t)
+            }
+
+            fn from_units<F: FnMut() -> f64>(
+                range: PropRange<Self>,
+                units: &mut F,
+            ) -> Option<Self> {
+                Some($slerp(
+                    range.start,
+                    range.end,
+                    units().clamp(0.0, 1.0) as f32,
+                ))
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                Some($slerp(*from, *to, t))
+            }
+
+            /// Any bounds can be slerped between, so there are no reversed or empty ranges.
+            fn validate_bounds(
+                start: &Self,
+                end: &Self,
+                _inclusive: bool,
+            ) -> Result<(), PropertyError> {
+                if start.is_finite() && end.is_finite() {
+                    Ok(())
+                } else {
+                    Err(PropertyError::NonFiniteBound)
+                }
+            }
+        }
+    };
+}
+
+/// Uniformly distributed rotation, see Ken Shoemake's "Uniform random rotations".
+fn random_quat<R: RngCore + ?Sized>(rng: &mut R) -> Quat {
+    let (u1, u2, u3): (f32, f32, f32) = (rng.gen(), rng.gen(), rng.gen());
+    let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+    Quat::from_xyzw(
+        a * (TAU * u2).sin(),
+        a * (TAU * u2).cos(),
+        b * (TAU * u3).sin(),
+        b * (TAU * u3).cos(),
+    )
+}
+
+fn random_rot2<R: RngCore + ?Sized>(rng: &mut R) -> Rot2 {
+    Rot2::radians(rng.gen_range(0.0..TAU))
+}
+
+/// Uniformly distributed on the sphere, picking the height and then the angle around it.
+fn random_dir3<R: RngCore + ?Sized>(rng: &mut R) -> Dir3 {
+    let z: f32 = rng.gen_range(-1.0..=1.0);
+    let (sin, cos) = rng.gen_range(0.0..TAU).sin_cos();
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    Dir3::new_unchecked(Vec3::new(r * cos, r * sin, z))
+}
+
+prop_rand_rotation_impl!(Quat, random_quat, Quat::slerp);
+prop_rand_rotation_impl!(Rot2, random_rot2, Rot2::slerp);
+prop_rand_rotation_impl!(Dir2, |rng: &mut R| random_rot2(rng) * Dir2::X, Dir2::slerp);
+prop_rand_rotation_impl!(Dir3, random_dir3, Dir3::slerp);
+
+// Matrices are treated as rotations, with Mat4 also interpolating its scale and translation.
+prop_rand_rotation_impl!(
+    Mat2,
+    |rng: &mut R| Mat2::from_angle(random_rot2(rng).as_radians()),
+    |start: Mat2, end: Mat2, t| {
+        let angle = |m: Mat2| m.x_axis.y.atan2(m.x_axis.x);
+        Mat2::from_angle(
+            Rot2::radians(angle(start))
+                .slerp(Rot2::radians(angle(end)), t)
+                .as_radians(),
+        )
+    }
+);
+prop_rand_rotation_impl!(
+    Mat3,
+    |rng: &mut R| Mat3::from_quat(random_quat(rng)),
+    |start: Mat3, end: Mat3, t| Mat3::from_quat(
+        Quat::from_mat3(&start).slerp(Quat::from_mat3(&end), t)
+    )
+);
+prop_rand_rotation_impl!(
+    Mat4,
+    |rng: &mut R| Mat4::from_quat(random_quat(rng)),
+    |start: Mat4, end: Mat4, t| {
+        let (start_scale, start_rotation, start_translation) =
+            start.to_scale_rotation_translation();
+        let (end_scale, end_rotation, end_translation) = end.to_scale_rotation_translation();
+        Mat4::from_scale_rotation_translation(
+            start_scale.lerp(end_scale, t),
+            start_rotation.slerp(end_rotation, t),
+            start_translation.lerp(end_translation, t),
+        )
+    }
+);
+
+/// Random produces an opaque color with uniformly random sRGB channels, converted into the color's
+/// space. Ranges interpolate between the start and end in the color's own space, taking the
+/// shortest path around the hue for hue based spaces.
+#[cfg(feature = "color")]
+macro_rules! prop_rand_color_impl {
+    ($color_type:tt) => {
+        impl PropRand for bevy_color::$color_type {
+            fn gen<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+                bevy_color::Srgba::new(rng.gen(), rng.gen(), rng.gen(), 1.0).into()
+            }
+
+            fn gen_range<R: RngCore + ?Sized>(rng: &mut R, range: PropRange<Self>) -> Self {
+                Self::gen_distribution(rng, range, &PropDistribution::Uniform)
+            }
+
+            fn gen_distribution<R: RngCore + ?Sized>(
+                rng: &mut R,
+                range: PropRange<Self>,
+                distribution: &PropDistribution,
+            ) -> Self {
+                let t = distribution.sample_unit(rng, range.inclusive) as f32;
+                bevy_color::Mix::mix(&range.start, &range.end, t)
+            }
+
+            fn from_units<F: FnMut() -> f64>(
+                range: PropRange<Self>,
+                units: &mut F,
+            ) -> Option<Self> {
+                let t = units().clamp(0.0, 1.0) as f32;
+                Some(bevy_color::Mix::mix(&range.start, &range.end, t))
+            }
+
+            fn interpolate(from: &Self, to: &Self, t: f32) -> Option<Self> {
+                Some(bevy_color::Mix::mix(from, to, t))
+            }
+
+            /// Any colors can be mixed, so there are no reversed or empty ranges.
+            fn validate_bounds(
+                start: &Self,
+                end: &Self,
+                _inclusive: bool,
+            ) -> Result<(), PropertyError> {
+                let finite = |color: &Self| {
+                    let linear = bevy_color::LinearRgba::from(bevy_color::Color::from(*color));
+                    bevy_color::ColorToComponents::to_f32_array(linear)
+                        .iter()
+                        .all(|component| component.is_finite())
+                };
+                if finite(start) && finite(end) {
+                    Ok(())
+                } else {
+                    Err(PropertyError::NonFiniteBound)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "color")]
+mod color_impls {
+    use super::*;
+
+    prop_rand_color_impl!(Color);
+    prop_rand_color_impl!(Srgba);
+    prop_rand_color_impl!(LinearRgba);
+    prop_rand_color_impl!(Hsla);
+    prop_rand_color_impl!(Hsva);
+    prop_rand_color_impl!(Hwba);
+    prop_rand_color_impl!(Laba);
+    prop_rand_color_impl!(Lcha);
+    prop_rand_color_impl!(Oklaba);
+    prop_rand_color_impl!(Oklcha);
+    prop_rand_color_impl!(Xyza);
 }
 
 /*impl PropRand for Vec2 {
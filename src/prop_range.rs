@@ -3,6 +3,9 @@ use bevy_reflect::Reflect;
 
 use std::ops::{Range, RangeInclusive};
 
+use crate::prop_rand::PropRand;
+use crate::property_error::PropertyError;
+
 /// Wrapper around [std::ops::Range] to be utilized by PropRand.
 #[derive(Clone, Default, Debug, Reflect)]
 pub struct PropRange<T> {
@@ -21,6 +24,18 @@ impl<T> PropRange<T> {
     }
 }
 
+impl<T: PropRand> PropRange<T> {
+    /// Checks that values can be generated from the range, see [PropRand::validate_bounds].
+    pub fn validate(&self) -> Result<(), PropertyError> {
+        T::validate_bounds(&self.start, &self.end, self.inclusive)
+    }
+
+    /// Swaps the bounds where the start is after the end, see [PropRand::fix_bounds].
+    pub fn fix_reversed_bounds(&mut self) {
+        T::fix_bounds(&mut self.start, &mut self.end);
+    }
+}
+
 impl<T> From<Range<T>> for PropRange<T> {
     fn from(v: Range<T>) -> Self {
         Self {
@@ -41,3 +56,40 @@ impl<T> From<RangeInclusive<T>> for PropRange<T> {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for PropRange<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        struct PropRangeRepr<'a, T> {
+            range: (&'a T, &'a T),
+            inclusive: bool,
+        }
+
+        PropRangeRepr {
+            range: (&self.start, &self.end),
+            inclusive: self.inclusive,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes from `{ range: [start, end], inclusive: bool }`, where `inclusive` defaults to
+/// false.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for PropRange<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct PropRangeRepr<T> {
+            range: (T, T),
+            #[serde(default)]
+            inclusive: bool,
+        }
+
+        let PropRangeRepr {
+            range: (start, end),
+            inclusive,
+        } = PropRangeRepr::deserialize(deserializer)?;
+        Ok(Self::new(start, end, inclusive))
+    }
+}
@@ -0,0 +1,135 @@
+use bevy_reflect::Reflect;
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+/// How a [PropSequence] steps through its choices.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum SequenceMode {
+    /// Cycles through the choices in order
+    #[default]
+    Sequence,
+
+    /// Picks every choice once, in a random order, before reshuffling for the next round
+    ShuffleBag,
+
+    /// Goes through the choices in order, then back in reverse, ie: `0, 1, 2, 1, 0, 1, ...`
+    PingPong,
+
+    /// Picks a random choice, but never the same one twice in a row
+    NoRepeat,
+}
+
+/// A list of choices stepped through according to a [SequenceMode].
+///
+/// Only [PropSequence::next] advances the sequence, [PropSequence::current] and
+/// [PropSequence::choose] read it without touching its state.
+#[derive(Clone, Default, Debug, Reflect)]
+pub struct PropSequence<T> {
+    choices: Vec<T>,
+    mode: SequenceMode,
+    /// Index of the next choice for [SequenceMode::Sequence] and [SequenceMode::PingPong].
+    index: usize,
+    reverse: bool,
+    /// Indices left in the current [SequenceMode::ShuffleBag] round, taken from the back.
+    bag: Vec<usize>,
+    last: Option<usize>,
+}
+
+impl<T> PropSequence<T> {
+    pub fn new(choices: Vec<T>, mode: SequenceMode) -> Self {
+        Self {
+            choices,
+            mode,
+            index: 0,
+            reverse: false,
+            bag: Vec::new(),
+            last: None,
+        }
+    }
+
+    /// The choices, in the order they were given.
+    pub fn choices(&self) -> &[T] {
+        &self.choices
+    }
+
+    pub fn mode(&self) -> SequenceMode {
+        self.mode
+    }
+
+    /// Restarts the sequence from the first choice, and starts a new round of the shuffle bag.
+    pub fn reset(&mut self) {
+        self.index = 0;
+        self.reverse = false;
+        self.bag.clear();
+        self.last = None;
+    }
+
+    /// The choice last returned by [PropSequence::next], or the first choice if it hasn't been
+    /// called since the sequence was created or reset. None if there are no choices.
+    pub fn current(&self) -> Option<&T> {
+        self.last
+            .and_then(|idx| self.choices.get(idx))
+            .or(self.choices.first())
+    }
+
+    /// Randomly selects a choice without advancing the sequence, or None if there are no choices.
+    pub fn choose<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        self.choices.choose(rng)
+    }
+
+    /// Advances the sequence and returns the choice it lands on, or None if there are no choices.
+    pub fn next<R: RngCore + ?Sized>(&mut self, rng: &mut R) -> Option<&T> {
+        let len = self.choices.len();
+        if len == 0 {
+            return None;
+        }
+        let idx = match self.mode {
+            SequenceMode::Sequence => {
+                let idx = self.index % len;
+                self.index = (idx + 1) % len;
+                idx
+            }
+            SequenceMode::ShuffleBag => {
+                if self.bag.is_empty() {
+                    self.bag.extend(0..len);
+                    self.bag.shuffle(rng);
+                }
+                // Choices may have been removed since the bag was filled.
+                self.bag.pop().filter(|idx| *idx < len).unwrap_or(0)
+            }
+            SequenceMode::PingPong => {
+                let idx = self.index.min(len - 1);
+                if len > 1 {
+                    if (self.reverse && idx == 0) || (!self.reverse && idx == len - 1) {
+                        self.reverse = !self.reverse;
+                    }
+                    self.index = if self.reverse { idx - 1 } else { idx + 1 };
+                }
+                idx
+            }
+            SequenceMode::NoRepeat => match self.last.filter(|last| *last < len) {
+                Some(last) if len > 1 => {
+                    let idx = rng.gen_range(0..len - 1);
+                    if idx >= last {
+                        idx + 1
+                    } else {
+                        idx
+                    }
+                }
+                _ => rng.gen_range(0..len),
+            },
+        };
+        self.last = Some(idx);
+        Some(&self.choices[idx])
+    }
+}
+
+impl<T> From<Vec<T>> for PropSequence<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self::new(v, SequenceMode::Sequence)
+    }
+}
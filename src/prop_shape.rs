@@ -0,0 +1,247 @@
+use bevy_math::{
+    primitives::*,
+    sampling::ShapeSample,
+    {Vec2, Vec3},
+};
+use bevy_reflect::Reflect;
+use rand::{Rng, RngCore};
+
+use crate::property_error::PropertyError;
+
+/// How many points are tried inside a mesh's bounds before falling back to its surface.
+const MESH_MAX_ATTEMPTS: usize = 64;
+
+/// A [bevy_math] primitive or a triangle mesh to sample points from, centered on the origin.
+///
+/// 2D shapes are sampled on the XY plane when a 3D point is requested. 3D shapes can only be
+/// sampled as 3D points.
+#[derive(Clone, Debug, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PropShape {
+    Circle(Circle),
+    CircularSector(CircularSector),
+    Annulus(Annulus),
+    Rhombus(Rhombus),
+    Rectangle(Rectangle),
+    Triangle2d(Triangle2d),
+    Capsule2d(Capsule2d),
+    Sphere(Sphere),
+    Cuboid(Cuboid),
+    Triangle3d(Triangle3d),
+    Tetrahedron(Tetrahedron),
+    Cylinder(Cylinder),
+    Capsule3d(Capsule3d),
+
+    /// The triangles of a mesh, ie: from `Mesh::triangles`. The boundary is the mesh's surface,
+    /// and the interior is only well defined for closed meshes.
+    Mesh(Vec<Triangle3d>),
+}
+
+impl PropShape {
+    /// Whether this shape produces 3D points.
+    pub fn is_3d(&self) -> bool {
+        matches!(
+            self,
+            PropShape::Sphere(_)
+                | PropShape::Cuboid(_)
+                | PropShape::Triangle3d(_)
+                | PropShape::Tetrahedron(_)
+                | PropShape::Cylinder(_)
+                | PropShape::Capsule3d(_)
+                | PropShape::Mesh(_)
+        )
+    }
+
+    /// Builds a [PropShape::Mesh] out of the triangles.
+    pub fn mesh(triangles: impl IntoIterator<Item = Triangle3d>) -> Self {
+        PropShape::Mesh(triangles.into_iter().collect())
+    }
+
+    /// Checks that points can be sampled from the shape, which fails for meshes without any area.
+    pub fn validate(&self) -> Result<(), PropertyError> {
+        match self {
+            PropShape::Mesh(triangles) => {
+                let area = mesh_area(triangles);
+                if area.is_finite() && area > 0.0 {
+                    Ok(())
+                } else {
+                    Err(PropertyError::EmptyShape)
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Same as [PropShape::validate], also failing for 3D shapes as they can't be sampled as 2D
+    /// points.
+    pub fn validate_2d(&self) -> Result<(), PropertyError> {
+        if self.is_3d() {
+            return Err(PropertyError::UnsupportedShape);
+        }
+        self.validate()
+    }
+
+    /// Uniformly samples a point inside the shape, or on its boundary if `boundary` is true.
+    ///
+    /// Points inside a mesh are found by sampling its bounds until one is inside, which falls
+    /// back to the surface if none are found, ie: for open meshes.
+    pub fn sample<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        boundary: bool,
+    ) -> Result<Vec3, PropertyError> {
+        let rng = &mut RngAdapter(rng);
+        macro_rules! sample {
+            ($shape:expr) => {
+                if boundary {
+                    $shape.sample_boundary(rng)
+                } else {
+                    $shape.sample_interior(rng)
+                }
+            };
+        }
+        Ok(match self {
+            PropShape::Circle(shape) => sample!(shape).extend(0.0),
+            PropShape::CircularSector(shape) => sample!(shape).extend(0.0),
+            PropShape::Annulus(shape) => sample!(shape).extend(0.0),
+            PropShape::Rhombus(shape) => sample!(shape).extend(0.0),
+            PropShape::Rectangle(shape) => sample!(shape).extend(0.0),
+            PropShape::Triangle2d(shape) => sample!(shape).extend(0.0),
+            PropShape::Capsule2d(shape) => sample!(shape).extend(0.0),
+            PropShape::Sphere(shape) => sample!(shape),
+            PropShape::Cuboid(shape) => sample!(shape),
+            PropShape::Triangle3d(shape) => sample!(shape),
+            PropShape::Tetrahedron(shape) => sample!(shape),
+            PropShape::Cylinder(shape) => sample!(shape),
+            PropShape::Capsule3d(shape) => sample!(shape),
+            PropShape::Mesh(triangles) => {
+                self.validate()?;
+                sample_mesh(triangles, rng.0, boundary)
+            }
+        })
+    }
+
+    /// Same as [PropShape::sample], for 2D shapes. Fails for 3D shapes.
+    pub fn sample_2d<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        boundary: bool,
+    ) -> Result<Vec2, PropertyError> {
+        self.validate_2d()?;
+        Ok(self.sample(rng, boundary)?.truncate())
+    }
+}
+
+fn mesh_area(triangles: &[Triangle3d]) -> f32 {
+    triangles.iter().map(Triangle3d::area).sum()
+}
+
+/// Picks a triangle weighted by area and samples a point on it, or tries points within the
+/// mesh's bounds for the interior.
+fn sample_mesh<R: RngCore + ?Sized>(triangles: &[Triangle3d], rng: &mut R, boundary: bool) -> Vec3 {
+    if !boundary {
+        let (min, max) = triangles
+            .iter()
+            .flat_map(|triangle| triangle.vertices)
+            .fold((Vec3::MAX, Vec3::MIN), |(min, max), v| {
+                (min.min(v), max.max(v))
+            });
+        for _ in 0..MESH_MAX_ATTEMPTS {
+            let t = Vec3::new(rng.gen(), rng.gen(), rng.gen());
+            let point = min + (max - min) * t;
+            if mesh_contains(triangles, point) {
+                return point;
+            }
+        }
+    }
+    let mut target = rng.gen_range(0.0..mesh_area(triangles));
+    let triangle = triangles
+        .iter()
+        .find(|triangle| {
+            target -= triangle.area();
+            target < 0.0
+        })
+        .or(triangles.last())
+        .unwrap();
+    triangle.sample_interior(&mut RngAdapter(rng))
+}
+
+/// Whether the point is inside a closed mesh, by counting the triangles a ray from it crosses.
+fn mesh_contains(triangles: &[Triangle3d], point: Vec3) -> bool {
+    // Skewed off the axes so the ray is unlikely to graze an edge shared by two triangles.
+    let dir = Vec3::new(1.0, 0.000_123, 0.000_457).normalize();
+    let crossings = triangles
+        .iter()
+        .filter(|triangle| {
+            // Möller–Trumbore ray-triangle intersection
+            let [a, b, c] = triangle.vertices;
+            let (ab, ac) = (b - a, c - a);
+            let p = dir.cross(ac);
+            let det = ab.dot(p);
+            if det.abs() < f32::EPSILON {
+                return false;
+            }
+            let to_point = point - a;
+            let u = to_point.dot(p) / det;
+            let q = to_point.cross(ab);
+            let v = dir.dot(q) / det;
+            u >= 0.0 && v >= 0.0 && u + v <= 1.0 && ac.dot(q) / det > 0.0
+        })
+        .count();
+    crossings % 2 == 1
+}
+
+macro_rules! prop_shape_from_impl {
+    ($($shape:tt,)+) => {
+        $(
+            impl From<$shape> for PropShape {
+                fn from(v: $shape) -> Self {
+                    PropShape::$shape(v)
+                }
+            }
+        )+
+    };
+}
+
+prop_shape_from_impl!(
+    Circle,
+    CircularSector,
+    Annulus,
+    Rhombus,
+    Rectangle,
+    Triangle2d,
+    Capsule2d,
+    Sphere,
+    Cuboid,
+    Triangle3d,
+    Tetrahedron,
+    Cylinder,
+    Capsule3d,
+);
+
+impl From<Vec<Triangle3d>> for PropShape {
+    fn from(v: Vec<Triangle3d>) -> Self {
+        PropShape::Mesh(v)
+    }
+}
+
+/// Lets the crate's rngs drive [ShapeSample], which is built on a newer version of `rand`.
+struct RngAdapter<'a, R: ?Sized>(&'a mut R);
+
+impl<R: RngCore + ?Sized> rand_core_09::RngCore for RngAdapter<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
@@ -0,0 +1,75 @@
+use bevy_reflect::Reflect;
+use rand::{Rng, RngCore};
+
+/// A list of choices with relative weights, sampled by walking the cumulative weights.
+///
+/// The weights are summed when sampling rather than cached, so edits made through reflection,
+/// ie: in an inspector or a scene file, apply right away. Negative and NaN weights are treated as
+/// zero.
+#[derive(Clone, Default, Debug, Reflect)]
+pub struct PropWeighted<T> {
+    choices: Vec<(T, f32)>,
+}
+
+impl<T> PropWeighted<T> {
+    pub fn new(choices: Vec<(T, f32)>) -> Self {
+        Self { choices }
+    }
+
+    /// The choices and their weights, in the order they were given.
+    pub fn choices(&self) -> &[(T, f32)] {
+        &self.choices
+    }
+
+    /// Sum of all of the weights.
+    pub fn total_weight(&self) -> f32 {
+        self.weights().sum()
+    }
+
+    fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        self.choices.iter().map(|(_, weight)| weight.max(0.0))
+    }
+
+    /// Randomly selects a choice based on the weights, or None if there are no choices or every
+    /// weight is zero.
+    pub fn choose<R: RngCore + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        let total = self.total_weight();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = rng.gen_range(0.0..total);
+        for ((choice, _), weight) in self.choices.iter().zip(self.weights()) {
+            if target < weight {
+                return Some(choice);
+            }
+            target -= weight;
+        }
+        // Rounding can leave the target just past the end, use the last weighted choice.
+        self.choices
+            .iter()
+            .rev()
+            .find(|(_, weight)| weight.max(0.0) > 0.0)
+            .map(|(choice, _)| choice)
+    }
+}
+
+impl<T> From<Vec<(T, f32)>> for PropWeighted<T> {
+    fn from(v: Vec<(T, f32)>) -> Self {
+        Self::new(v)
+    }
+}
+
+/// Serializes as the list of `(value, weight)` pairs.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for PropWeighted<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.choices.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for PropWeighted<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<(T, f32)>::deserialize(deserializer).map(Self::new)
+    }
+}
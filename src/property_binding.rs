@@ -0,0 +1,173 @@
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::{GetPath, PartialReflect, Reflect};
+use bevy_time::Time;
+use log::warn;
+
+use crate::dynamic_property::DynamicProperty;
+use crate::interval_property::{IntervalProperty, PropertyTimeScale};
+use crate::rng::{PropertyRng, PropertyRngSeed};
+
+/// Binds an [IntervalProperty] to a field of any reflected component on the same entity, without
+/// implementing [IntervalPropertyComponent](crate::interval_property::IntervalPropertyComponent).
+///
+/// The property is a [DynamicProperty], so bindings generating different types share one
+/// system. The component is looked up by type path in the [AppTypeRegistry], so it must be
+/// registered with `#[reflect(Component)]`, ie:
+/// `PropertyBinding::new(IntervalProperty::new(DynamicProperty::new(Property::from(-1.0f32..1.0)),
+/// 0.5), "Transform", "translation.x")`.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PropertyBinding {
+    pub property: IntervalProperty<DynamicProperty>,
+    /// The full or short type path of the component, ie:
+    /// `bevy_transform::components::transform::Transform` or `Transform`
+    pub component: String,
+    /// The reflect path of the field within the component, ie: `translation.x`
+    pub field: String,
+    /// Why the last value couldn't be applied, logged once until it changes.
+    #[reflect(ignore)]
+    error: Option<PropertyBindingError>,
+}
+
+impl PropertyBinding {
+    pub fn new(
+        property: IntervalProperty<DynamicProperty>,
+        component: impl Into<String>,
+        field: impl Into<String>,
+    ) -> Self {
+        Self {
+            property,
+            component: component.into(),
+            field: field.into(),
+            error: None,
+        }
+    }
+
+    /// Why the last generated value couldn't be applied, or None if it was.
+    pub fn error(&self) -> Option<&PropertyBindingError> {
+        self.error.as_ref()
+    }
+}
+
+/// Why a value couldn't be applied by [apply_to_field].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PropertyBindingError {
+    #[error("no type is registered with the path `{0}`")]
+    UnknownType(String),
+    #[error("`{0}` isn't registered with `#[reflect(Component)]`")]
+    NotAComponent(String),
+    #[error("entity {entity} doesn't have the component `{component}`")]
+    MissingComponent { entity: Entity, component: String },
+    #[error("invalid field path `{path}`: {message}")]
+    InvalidPath { path: String, message: String },
+    #[error("the value couldn't be applied to `{path}`: {message}")]
+    Apply { path: String, message: String },
+}
+
+type PropertyBindingQueryData = (
+    Entity,
+    &'static mut PropertyBinding,
+    Option<&'static mut PropertyRngSeed>,
+    Option<&'static PropertyTimeScale>,
+);
+
+impl PropertyBinding {
+    /// Ticks each binding's property and queues commands applying new values to the bound fields,
+    /// with the rng picked the same way as for an
+    /// [IntervalPropertyComponent](crate::interval_property::IntervalPropertyComponent).
+    ///
+    /// Values that can't be applied, ie: to unregistered components, missing components or
+    /// invalid fields, are skipped with a warning logged once per binding until the error
+    /// changes, see [PropertyBinding::error].
+    pub fn system(
+        query: Query<PropertyBindingQueryData>,
+        time: Res<Time>,
+        global_rng: Option<ResMut<PropertyRng>>,
+        commands: Commands,
+    ) {
+        Self::system_with_clock(query, time, global_rng, commands);
+    }
+
+    /// Same as [PropertyBinding::system], but ticks with the given clock.
+    pub fn system_with_clock<C: Default + Send + Sync + 'static>(
+        mut query: Query<PropertyBindingQueryData>,
+        time: Res<Time<C>>,
+        mut global_rng: Option<ResMut<PropertyRng>>,
+        mut commands: Commands,
+    ) {
+        for (entity, mut binding, maybe_seed, maybe_scale) in query.iter_mut() {
+            let delta = match maybe_scale {
+                Some(scale) => time.delta().mul_f32(scale.0.max(0.0)),
+                None => time.delta(),
+            };
+            let value = match (maybe_seed, global_rng.as_deref_mut()) {
+                (Some(mut seed), global_rng) => {
+                    let rng = seed.rng(global_rng.map(|global_rng| global_rng.seed()));
+                    binding.property.tick_value_with(delta, rng).cloned()
+                }
+                (None, Some(global_rng)) => {
+                    binding.property.tick_value_with(delta, global_rng).cloned()
+                }
+                (None, None) => binding.property.tick_value(delta).cloned(),
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            let (component, field) = (binding.component.clone(), binding.field.clone());
+            commands.queue(move |world: &mut World| {
+                let error = apply_to_field(world, entity, &component, &field, &*value).err();
+                let Some(mut binding) = world.get_mut::<PropertyBinding>(entity) else {
+                    return;
+                };
+                if binding.error == error {
+                    return;
+                }
+                if let Some(error) = &error {
+                    warn!("PropertyBinding on {entity} couldn't apply a value: {error}");
+                }
+                binding.error = error;
+            });
+        }
+    }
+}
+
+/// Applies the value to the field at the reflect path of the component on the entity, with the
+/// component looked up the same way as for a [PropertyBinding].
+pub fn apply_to_field(
+    world: &mut World,
+    entity: Entity,
+    component: &str,
+    field: &str,
+    value: &dyn PartialReflect,
+) -> Result<(), PropertyBindingError> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = registry
+        .get_with_type_path(component)
+        .or_else(|| registry.get_with_short_type_path(component))
+        .ok_or_else(|| PropertyBindingError::UnknownType(component.to_string()))?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| PropertyBindingError::NotAComponent(component.to_string()))?;
+    let missing = || PropertyBindingError::MissingComponent {
+        entity,
+        component: component.to_string(),
+    };
+    let entity_mut = world.get_entity_mut(entity).map_err(|_| missing())?;
+    let mut target = reflect_component
+        .reflect_mut(entity_mut)
+        .ok_or_else(missing)?;
+    let target =
+        target
+            .reflect_path_mut(field)
+            .map_err(|err| PropertyBindingError::InvalidPath {
+                path: field.to_string(),
+                message: err.to_string(),
+            })?;
+    target
+        .try_apply(value)
+        .map_err(|err| PropertyBindingError::Apply {
+            path: field.to_string(),
+            message: err.to_string(),
+        })
+}
@@ -0,0 +1,32 @@
+/// Why values can't be generated from a [crate::Property], see [crate::Property::validate].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PropertyError {
+    #[error("the range is empty, its start and end are equal but it isn't inclusive")]
+    EmptyRange,
+    #[error("the range's start is after its end")]
+    ReversedBounds,
+    #[error("the range has a NaN or infinite bound, or its bounds are too far apart to sample")]
+    NonFiniteBound,
+    #[error("there are no choices to pick from")]
+    EmptyChoices,
+    #[error("every choice has a weight of zero")]
+    ZeroWeights,
+    #[error("a choice has a NaN or infinite weight, or the weights add up to infinity")]
+    NonFiniteWeight,
+    #[error("points of this type can't be sampled from the shape")]
+    UnsupportedShape,
+    #[error("the shape has no area to sample points from")]
+    EmptyShape,
+    #[error("the distribution has a NaN or infinite parameter")]
+    NonFiniteParameter,
+    #[error("the normal distribution's standard deviation isn't positive")]
+    NonPositiveStdDev,
+    #[error("the triangular distribution's mode is outside 0.0..=1.0")]
+    ModeOutOfRange,
+    #[error("the cumulative distribution function has fewer than two values")]
+    EmptyCdf,
+    #[error("the cumulative distribution function decreases")]
+    DecreasingCdf,
+    #[error("values of this type can only be sampled from a uniform distribution")]
+    UnsupportedDistribution,
+}
@@ -0,0 +1,178 @@
+use bevy_ecs::{
+    component::Mutable,
+    prelude::*,
+    query::{QueryData, QueryItem},
+    reflect::ReflectComponent,
+    system::SystemParam,
+};
+use bevy_reflect::Reflect;
+use std::marker::PhantomData;
+
+use crate::interval_property::IntervalPropertyComponent;
+use crate::variable_property::VariableProperty;
+
+/// Which entities an [IntervalPropertyComponent] updates, see
+/// [IntervalPropertyComponent::target].
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum PropertyTargetMode {
+    /// The entity the property is on
+    #[default]
+    This,
+
+    /// The entity's parent, through [ChildOf]
+    Parent,
+
+    /// Each of the entity's [Children]
+    Children,
+
+    /// The given entity, ie: from a field of the property component
+    Entity(Entity),
+
+    /// Each entity related to this one through [PropertyTargetOf]
+    Related,
+}
+
+/// Marks the entity as a target of the interval properties on the given entity, for
+/// [PropertyTargetMode::Related].
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+#[relationship(relationship_target = PropertyTargets)]
+pub struct PropertyTargetOf(pub Entity);
+
+/// The entities targeted by the interval properties on this entity, see [PropertyTargetOf].
+#[derive(Component, Reflect, Default, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+#[relationship_target(relationship = PropertyTargetOf)]
+pub struct PropertyTargets(Vec<Entity>);
+
+/// The components updated by an [IntervalPropertyComponent].
+///
+/// Implemented for any mutable component, and for tuples of them through [TargetComponents].
+pub trait PropertyTargetData {
+    type Data: QueryData + 'static;
+
+    /// What [IntervalPropertyComponent::update] is given.
+    type Target<'w>;
+
+    fn with_target<'w, R>(
+        item: QueryItem<'w, '_, Self::Data>,
+        f: impl FnOnce(&mut Self::Target<'w>) -> R,
+    ) -> R;
+}
+
+impl<C: Component<Mutability = Mutable>> PropertyTargetData for C {
+    type Data = &'static mut C;
+    type Target<'w> = C;
+
+    fn with_target<'w, R>(
+        mut item: QueryItem<'w, '_, Self::Data>,
+        f: impl FnOnce(&mut Self::Target<'w>) -> R,
+    ) -> R {
+        f(&mut item)
+    }
+}
+
+/// A tuple of components updated together, ie: `TargetComponents<(Transform, Sprite)>`, where
+/// [IntervalPropertyComponent::update] is given `&mut (Mut<Transform>, Mut<Sprite>)`.
+///
+/// Entities missing any of the components aren't updated.
+pub struct TargetComponents<T>(PhantomData<T>);
+
+macro_rules! property_target_data_tuple_impl {
+    ($($type:ident),+) => {
+        impl<$($type: Component<Mutability = Mutable>),+> PropertyTargetData
+            for TargetComponents<($($type,)+)>
+        {
+            type Data = ($(&'static mut $type,)+);
+            type Target<'w> = ($(Mut<'w, $type>,)+);
+
+            fn with_target<'w, R>(
+                mut item: QueryItem<'w, '_, Self::Data>,
+                f: impl FnOnce(&mut Self::Target<'w>) -> R,
+            ) -> R {
+                f(&mut item)
+            }
+        }
+    };
+}
+
+property_target_data_tuple_impl!(A);
+property_target_data_tuple_impl!(A, B);
+property_target_data_tuple_impl!(A, B, C);
+property_target_data_tuple_impl!(A, B, C, D);
+property_target_data_tuple_impl!(A, B, C, D, E);
+property_target_data_tuple_impl!(A, B, C, D, E, F);
+property_target_data_tuple_impl!(A, B, C, D, E, F, G);
+property_target_data_tuple_impl!(A, B, C, D, E, F, G, H);
+
+/// Finds and updates the targets of the [IntervalPropertyComponent] `T`.
+#[derive(SystemParam)]
+pub struct PropertyTargetQuery<'w, 's, T: IntervalPropertyComponent> {
+    targets: Query<
+        'w,
+        's,
+        <<T as IntervalPropertyComponent>::TargetComponent as PropertyTargetData>::Data,
+    >,
+    relations: Query<
+        'w,
+        's,
+        (
+            Option<&'static ChildOf>,
+            Option<&'static Children>,
+            Option<&'static PropertyTargets>,
+        ),
+    >,
+}
+
+impl<T: IntervalPropertyComponent> PropertyTargetQuery<'_, '_, T> {
+    /// The entities targeted from `entity` with the given mode, whether or not they have the
+    /// target components.
+    pub fn resolve(
+        &self,
+        entity: Entity,
+        mode: PropertyTargetMode,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        resolve_targets(&self.relations, entity, mode)
+    }
+
+    /// Runs [IntervalPropertyComponent::update] on each target of `entity` with the target
+    /// components.
+    pub fn update(
+        &mut self,
+        entity: Entity,
+        mode: PropertyTargetMode,
+        new_value: &<T::Property as VariableProperty>::Output,
+    ) {
+        for target in resolve_targets(&self.relations, entity, mode) {
+            if let Ok(item) = self.targets.get_mut(target) {
+                T::TargetComponent::with_target(item, |target| T::update(new_value, target));
+            }
+        }
+    }
+}
+
+/// Borrows the targets from the relationship components instead of collecting them, as this runs
+/// for every property component each tick.
+fn resolve_targets<'a>(
+    relations: &'a Query<(
+        Option<&'static ChildOf>,
+        Option<&'static Children>,
+        Option<&'static PropertyTargets>,
+    )>,
+    entity: Entity,
+    mode: PropertyTargetMode,
+) -> impl Iterator<Item = Entity> + 'a {
+    let (single, many): (Option<Entity>, &[Entity]) = match (relations.get(entity), mode) {
+        (Err(_), _) => (None, &[]),
+        (Ok(_), PropertyTargetMode::This) => (Some(entity), &[]),
+        (Ok((parent, ..)), PropertyTargetMode::Parent) => (parent.map(ChildOf::parent), &[]),
+        (Ok((_, children, _)), PropertyTargetMode::Children) => {
+            (None, children.map_or(&[], |children| children))
+        }
+        (Ok(_), PropertyTargetMode::Entity(target)) => (Some(target), &[]),
+        (Ok((.., related)), PropertyTargetMode::Related) => {
+            (None, related.map_or(&[], |related| &related.0))
+        }
+    };
+    single.into_iter().chain(many.iter().copied())
+}
@@ -0,0 +1,105 @@
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_reflect::Reflect;
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+/// Global rng used by [crate::interval_property::IntervalPropertyComponent::system] in place of
+/// the thread-local rng when it is inserted as a resource.
+///
+/// Entities are sampled in query iteration order, so for values that don't depend on scheduling
+/// at all, give the entity a [PropertyRngSeed].
+#[derive(Resource, Clone, Debug)]
+pub struct PropertyRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl PropertyRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// The seed this rng was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// Seeds from the thread-local rng.
+impl Default for PropertyRng {
+    fn default() -> Self {
+        Self::new(rand::thread_rng().gen())
+    }
+}
+
+impl RngCore for PropertyRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Gives an entity its own rng stream, so the values generated for it only depend on this seed
+/// and the seed of the [PropertyRng] resource, if there is one.
+///
+/// Only the seed is reflected, so cloning through reflection or saving and loading a scene
+/// restarts the stream from the beginning.
+#[derive(Component, Reflect, Clone, Debug, Default)]
+#[reflect(Component)]
+pub struct PropertyRngSeed {
+    seed: u64,
+    /// The stream, with the global seed it was created from.
+    #[reflect(ignore)]
+    rng: Option<(Option<u64>, StdRng)>,
+}
+
+impl PropertyRngSeed {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: None }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the entity's rng stream, creating it on first use from this seed mixed with
+    /// `global_seed`.
+    ///
+    /// The stream restarts when `global_seed` differs from the one it was created with, ie: when
+    /// a [PropertyRng] is inserted, removed or replaced.
+    pub fn rng(&mut self, global_seed: Option<u64>) -> &mut StdRng {
+        if self
+            .rng
+            .as_ref()
+            .is_none_or(|(from, _)| *from != global_seed)
+        {
+            let mixed = match global_seed {
+                Some(global_seed) => splitmix64(global_seed ^ splitmix64(self.seed)),
+                None => splitmix64(self.seed),
+            };
+            self.rng = Some((global_seed, StdRng::seed_from_u64(mixed)));
+        }
+        &mut self.rng.as_mut().unwrap().1
+    }
+}
+
+/// Scrambles nearby seeds into unrelated ones before they are combined.
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
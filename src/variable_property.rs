@@ -1,17 +1,127 @@
 use array_macro::array;
 use bevy_reflect::TypePath;
 use paste::paste;
+use rand::{thread_rng, RngCore};
 
+use std::ops::{Add, Mul};
+
+use crate::combinators::*;
+use crate::prop_rand::PropRand;
+
+pub use bevy_variable_property_macros::VariableProperty;
+
+/// Generates values of [VariableProperty::Output]. The rng is passed as a `dyn RngCore`, so
+/// properties with the same output can be boxed together, ie:
+/// `Vec<Box<dyn VariableProperty<Output = f32>>>`.
 pub trait VariableProperty {
     type Output: TypePath;
-    fn get_value(&self) -> Self::Output;
+
+    /// Generates a value using the given rng. Supplying a seeded rng makes the generated values
+    /// reproducible.
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output;
+
+    /// Generates a value using the thread-local rng.
+    fn get_value(&self) -> Self::Output {
+        self.get_value_with(&mut thread_rng())
+    }
+
+    /// Generates the next value using the given rng, advancing any state the property keeps, ie:
+    /// the position of a [Property::Sequence](crate::Property::Sequence). Stateless properties
+    /// generate the same as [VariableProperty::get_value_with].
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+        self.get_value_with(rng)
+    }
+
+    /// Generates the next value using the thread-local rng. See [VariableProperty::next_value_with].
+    fn next_value(&mut self) -> Self::Output {
+        self.next_value_with(&mut thread_rng())
+    }
+
+    /// Returns `from` at `t` of 0.0 and `to` at 1.0, used to ease between generated values, see
+    /// [IntervalProperty::set_transition](crate::interval_property::IntervalProperty::set_transition).
+    ///
+    /// None if the values can't be interpolated, which is the default. Properties generating a
+    /// [PropRand] value forward to [PropRand::interpolate].
+    fn interpolate(_from: &Self::Output, _to: &Self::Output, _t: f32) -> Option<Self::Output>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Applies `f` to each generated value.
+    fn map<U: PropRand + TypePath>(self, f: fn(Self::Output) -> U) -> PropMap<Self, U>
+    where
+        Self: Sized,
+    {
+        PropMap { property: self, f }
+    }
+
+    /// Generates a value from both properties as a tuple.
+    fn zip<B: VariableProperty>(self, other: B) -> PropZip<Self, B>
+    where
+        Self: Sized,
+    {
+        PropZip { a: self, b: other }
+    }
+
+    /// Adds the values of both properties, ie: an anchor plus a random offset.
+    fn add<B: VariableProperty>(self, other: B) -> PropAdd<Self, B>
+    where
+        Self: Sized,
+        Self::Output: Add<B::Output, Output: PropRand>,
+    {
+        PropAdd { a: self, b: other }
+    }
+
+    /// Multiplies the values of both properties, ie: a base value times a random multiplier.
+    fn mul<B: VariableProperty>(self, other: B) -> PropMul<Self, B>
+    where
+        Self: Sized,
+        Self::Output: Mul<B::Output, Output: PropRand>,
+    {
+        PropMul { a: self, b: other }
+    }
+
+    /// Clamps each generated value between `min` and `max`.
+    fn clamp(self, min: Self::Output, max: Self::Output) -> PropClamp<Self>
+    where
+        Self: Sized,
+        Self::Output: ClampValue,
+    {
+        PropClamp {
+            property: self,
+            min,
+            max,
+        }
+    }
+
+    /// Builds a property out of each generated value with `f`, and generates the output from it.
+    fn then<Q: VariableProperty>(self, f: fn(Self::Output) -> Q) -> PropThen<Self, Q>
+    where
+        Self: Sized,
+    {
+        PropThen { property: self, f }
+    }
 }
 
 impl<T: TypePath, U: VariableProperty<Output = T>, const N: usize> VariableProperty for [U; N] {
     type Output = [T; N];
 
-    fn get_value(&self) -> [T; N] {
-        array![i => self[i].get_value(); N]
+    fn get_value_with(&self, rng: &mut dyn RngCore) -> [T; N] {
+        array![i => self[i].get_value_with(rng); N]
+    }
+
+    fn next_value_with(&mut self, rng: &mut dyn RngCore) -> [T; N] {
+        array![i => self[i].next_value_with(rng); N]
+    }
+
+    fn interpolate(from: &[T; N], to: &[T; N], t: f32) -> Option<[T; N]> {
+        let values = array![i => U::interpolate(&from[i], &to[i], t); N];
+        if values.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(values.map(Option::unwrap))
     }
 }
 
@@ -29,12 +139,36 @@ macro_rules! reverse_types_output {
 
 macro_rules! reverse_get_value {
     () => {};
-    ($self:ident, [$($list:literal,)*], $head:literal, $($tail:literal,)*) => {
-       reverse_get_value!($self, [$head, $($list,)*], $($tail,)*)
+    ($self:ident, $rng:ident, [$($list:literal,)*], $head:literal, $($tail:literal,)*) => {
+       reverse_get_value!($self, $rng, [$head, $($list,)*], $($tail,)*)
     };
-    ($self:ident, [$($list:literal,)+],) => {
+    ($self:ident, $rng:ident, [$($list:literal,)+],) => {
         paste! {(
-            $($self.$list.get_value(),)+
+            $($self.$list.get_value_with($rng),)+
+        )}
+    };
+}
+
+macro_rules! reverse_next_value {
+    () => {};
+    ($self:ident, $rng:ident, [$($list:literal,)*], $head:literal, $($tail:literal,)*) => {
+       reverse_next_value!($self, $rng, [$head, $($list,)*], $($tail,)*)
+    };
+    ($self:ident, $rng:ident, [$($list:literal,)+],) => {
+        paste! {(
+            $($self.$list.next_value_with($rng),)+
+        )}
+    };
+}
+
+macro_rules! reverse_interpolate {
+    () => {};
+    ($from:ident, $to:ident, $t:ident, [$($list_idx:literal $list:tt,)*], $head_idx:literal $head:tt, $($tail_idx:literal $tail:tt,)*) => {
+       reverse_interpolate!($from, $to, $t, [$head_idx $head, $($list_idx $list,)*], $($tail_idx $tail,)*)
+    };
+    ($from:ident, $to:ident, $t:ident, [$($idx:literal $list:tt,)+],) => {
+        paste! {(
+            $($list::interpolate(&$from.$idx, &$to.$idx, $t)?,)+
         )}
     };
 }
@@ -57,8 +191,14 @@ macro_rules! variable_property_impls {
 
         impl<$head_type: VariableProperty,$($type: VariableProperty,)*> VariableProperty for reverse_types!([], $head_type, $($type,)*) {
             type Output = reverse_types_output!([], $head_type, $($type,)*);
-            fn get_value(&self) -> Self::Output {
-                reverse_get_value!(self, [], $head_idx, $($idx,)*)
+            fn get_value_with(&self, rng: &mut dyn RngCore) -> Self::Output {
+                reverse_get_value!(self, rng, [], $head_idx, $($idx,)*)
+            }
+            fn next_value_with(&mut self, rng: &mut dyn RngCore) -> Self::Output {
+                reverse_next_value!(self, rng, [], $head_idx, $($idx,)*)
+            }
+            fn interpolate(from: &Self::Output, to: &Self::Output, t: f32) -> Option<Self::Output> {
+                Some(reverse_interpolate!(from, to, t, [], $head_idx $head_type, $($idx $type,)*))
             }
         }
 